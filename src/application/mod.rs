@@ -1,3 +1,3 @@
 pub mod use_cases;
 
-pub use use_cases::{ScanSystemUseCase, CleanSelectedCategoriesUseCase};
+pub use use_cases::{ScanSystemUseCase, CleanSelectedCategoriesUseCase, CategorySelection};
@@ -1,8 +1,34 @@
 use crate::domain::repositories::CleanerRepository;
-use crate::domain::entities::ScanResult;
+use crate::domain::entities::{ScanResult, CleanReport};
+use crate::domain::value_objects::CancellationToken;
 use anyhow::{Result, anyhow};
 use std::sync::Arc;
 
+/// Seleção de limpeza de uma categoria do resultado da varredura.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategorySelection {
+    category_index: usize,
+    item_indices: Option<Vec<usize>>,
+}
+
+impl CategorySelection {
+    /// Seleciona a categoria inteira.
+    pub fn whole(category_index: usize) -> Self {
+        Self {
+            category_index,
+            item_indices: None,
+        }
+    }
+
+    /// Seleciona apenas alguns itens da categoria.
+    pub fn items(category_index: usize, item_indices: Vec<usize>) -> Self {
+        Self {
+            category_index,
+            item_indices: Some(item_indices),
+        }
+    }
+}
+
 /// Caso de uso: Limpar categorias selecionadas.
 /// Coordena a limpeza de múltiplas categorias de forma segura.
 pub struct CleanSelectedCategoriesUseCase {
@@ -20,19 +46,38 @@ impl CleanSelectedCategoriesUseCase {
     /// 
     /// # Argumentos
     /// * `scan_result` - Resultado da varredura com as categorias
-    /// * `selected_indices` - Índices das categorias selecionadas para limpeza
+    /// * `selections` - Categorias (ou itens) selecionados para limpeza.
+    ///   Categorias que exigem revisão só são limpas item a item.
+    /// * `cancellation` - Token que interrompe a limpeza entre itens
     /// 
     /// # Retorna
-    /// Total de itens limpos com sucesso
-    pub fn execute(&self, scan_result: &ScanResult, selected_indices: &[usize]) -> Result<usize> {
-        let mut total_cleaned = 0;
+    /// Relatório com os itens removidos com sucesso
+    pub fn execute(
+        &self,
+        scan_result: &ScanResult,
+        selections: &[CategorySelection],
+        cancellation: &CancellationToken,
+    ) -> Result<CleanReport> {
+        let mut report = CleanReport::new();
+
+        for selection in selections {
+            if cancellation.is_cancelled() {
+                report.mark_cancelled();
+                break;
+            }
 
-        for &index in selected_indices {
+            let index = selection.category_index;
             if let Some(category) = scan_result.categories().get(index) {
-                if !category.is_empty() && self.cleaner_repository.can_clean(category) {
-                    match self.cleaner_repository.clean_category(category) {
-                        Ok(cleaned_count) => {
-                            total_cleaned += cleaned_count;
+                let category = match &selection.item_indices {
+                    Some(item_indices) => category.subset(item_indices),
+                    None if category.requires_review() => continue,
+                    None => category.clone(),
+                };
+
+                if !category.is_empty() && self.cleaner_repository.can_clean(&category) {
+                    match self.cleaner_repository.clean_category(&category, cancellation) {
+                        Ok(category_report) => {
+                            report.merge(category_report);
                         }
                         Err(e) => {
                             // Log erro mas continua limpando outras categorias
@@ -45,7 +90,7 @@ impl CleanSelectedCategoriesUseCase {
             }
         }
 
-        Ok(total_cleaned)
+        Ok(report)
     }
 }
 
@@ -53,12 +98,17 @@ impl CleanSelectedCategoriesUseCase {
 mod tests {
     use super::*;
     use crate::domain::entities::{CleanableCategory, CleanableItem};
+    use crate::domain::value_objects::CategoryType;
 
     struct MockCleanerRepository;
 
     impl CleanerRepository for MockCleanerRepository {
-        fn clean_category(&self, _category: &CleanableCategory) -> Result<usize> {
-            Ok(5)
+        fn clean_category(&self, category: &CleanableCategory, _cancellation: &CancellationToken) -> Result<CleanReport> {
+            let mut report = CleanReport::new();
+            for item in category.items() {
+                report.record_removed(item.path().to_string());
+            }
+            Ok(report)
         }
 
         fn can_clean(&self, _category: &CleanableCategory) -> bool {
@@ -76,7 +126,47 @@ mod tests {
         cat.add_item(CleanableItem::new("/tmp/test.log".to_string(), 1024));
         result.add_category(cat);
         
-        let cleaned = use_case.execute(&result, &[0]).unwrap();
-        assert_eq!(cleaned, 5);
+        let report = use_case.execute(&result, &[CategorySelection::whole(0)], &CancellationToken::new()).unwrap();
+        assert_eq!(report.removed(), ["/tmp/test.log"]);
+    }
+
+    #[test]
+    fn should_stop_before_next_category_when_cancelled() {
+        let repo = Arc::new(MockCleanerRepository);
+        let use_case = CleanSelectedCategoriesUseCase::new(repo);
+
+        let mut result = ScanResult::new();
+        let mut cat = CleanableCategory::new("Test".to_string());
+        cat.add_item(CleanableItem::new("/tmp/test.log".to_string(), 1024));
+        result.add_category(cat);
+
+        let cancellation = CancellationToken::new();
+        cancellation.cancel();
+
+        let report = use_case.execute(&result, &[CategorySelection::whole(0)], &cancellation).unwrap();
+        assert!(report.is_cancelled());
+        assert_eq!(report.removed_count(), 0);
+    }
+
+    #[test]
+    fn should_only_clean_selected_items_of_review_categories() {
+        let repo = Arc::new(MockCleanerRepository);
+        let use_case = CleanSelectedCategoriesUseCase::new(repo);
+
+        let mut result = ScanResult::new();
+        let mut cat = CleanableCategory::for_type(CategoryType::LargeFiles);
+        cat.add_item(CleanableItem::new("/home/dev/a.iso".to_string(), 1024));
+        cat.add_item(CleanableItem::new("/home/dev/b.iso".to_string(), 2048));
+        result.add_category(cat);
+
+        let report = use_case
+            .execute(&result, &[CategorySelection::whole(0)], &CancellationToken::new())
+            .unwrap();
+        assert_eq!(report.removed_count(), 0);
+
+        let report = use_case
+            .execute(&result, &[CategorySelection::items(0, vec![1])], &CancellationToken::new())
+            .unwrap();
+        assert_eq!(report.removed(), ["/home/dev/b.iso"]);
     }
 }
@@ -2,4 +2,4 @@ pub mod scan_system_use_case;
 pub mod clean_selected_categories_use_case;
 
 pub use scan_system_use_case::ScanSystemUseCase;
-pub use clean_selected_categories_use_case::CleanSelectedCategoriesUseCase;
+pub use clean_selected_categories_use_case::{CleanSelectedCategoriesUseCase, CategorySelection};
@@ -1,5 +1,6 @@
-use crate::domain::repositories::ScannerRepository;
+use crate::domain::repositories::{ScannerRepository, ScanProgressObserver};
 use crate::domain::entities::ScanResult;
+use crate::domain::value_objects::CancellationToken;
 use anyhow::Result;
 use std::sync::Arc;
 
@@ -16,9 +17,14 @@ impl ScanSystemUseCase {
         }
     }
 
-    /// Executa a varredura completa do sistema.
-    pub fn execute(&self) -> Result<ScanResult> {
-        self.scanner_repository.scan_system()
+    /// Executa a varredura completa notificando o progresso ao observador.
+    /// A varredura pode ser interrompida pelo token de cancelamento.
+    pub fn execute_with_progress(
+        &self,
+        observer: &dyn ScanProgressObserver,
+        cancellation: &CancellationToken,
+    ) -> Result<ScanResult> {
+        self.scanner_repository.scan_system_with_progress(observer, cancellation)
     }
 }
 
@@ -26,12 +32,23 @@ impl ScanSystemUseCase {
 mod tests {
     use super::*;
     use crate::domain::entities::{CleanableCategory, CleanableItem};
+    use crate::domain::repositories::NoScanProgress;
     use crate::domain::value_objects::CategoryType;
 
+    use crate::domain::value_objects::ScanProgress;
+    use std::sync::Mutex;
+
     struct MockScannerRepository;
 
     impl ScannerRepository for MockScannerRepository {
-        fn scan_system(&self) -> Result<ScanResult> {
+        fn scan_system_with_progress(
+            &self,
+            observer: &dyn ScanProgressObserver,
+            _cancellation: &CancellationToken,
+        ) -> Result<ScanResult> {
+            observer.on_progress(ScanProgress::CategoryStarted {
+                category: CategoryType::LogFiles,
+            });
             let mut result = ScanResult::new();
             result.add_category(CleanableCategory::new("Test".to_string()));
             Ok(result)
@@ -47,7 +64,29 @@ mod tests {
         let repo = Arc::new(MockScannerRepository);
         let use_case = ScanSystemUseCase::new(repo);
         
-        let result = use_case.execute();
+        let result = use_case.execute_with_progress(&NoScanProgress, &CancellationToken::new());
         assert!(result.is_ok());
     }
+
+    #[derive(Default)]
+    struct RecordingObserver {
+        events: Mutex<Vec<ScanProgress>>,
+    }
+
+    impl ScanProgressObserver for RecordingObserver {
+        fn on_progress(&self, progress: ScanProgress) {
+            self.events.lock().unwrap().push(progress);
+        }
+    }
+
+    #[test]
+    fn should_forward_progress_to_observer() {
+        let repo = Arc::new(MockScannerRepository);
+        let use_case = ScanSystemUseCase::new(repo);
+        let observer = RecordingObserver::default();
+
+        use_case.execute_with_progress(&observer, &CancellationToken::new()).unwrap();
+
+        assert_eq!(observer.events.lock().unwrap().len(), 1);
+    }
 }
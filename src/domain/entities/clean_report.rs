@@ -0,0 +1,107 @@
+/// Representa o resultado de uma limpeza.
+/// Registra exatamente quais itens foram removidos, inclusive quando a limpeza é cancelada.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CleanReport {
+    removed: Vec<String>,
+    partial: Vec<PartialItem>,
+    skipped: Vec<SkippedItem>,
+    cancelled: bool,
+}
+
+/// Diretório do qual só parte do conteúdo foi removida, pois algo dentro
+/// dele estava protegido. O que ficou aparece entre os itens ignorados.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialItem {
+    pub path: String,
+    pub freed_bytes: u64,
+}
+
+/// Item deixado de fora da limpeza e o motivo (ex.: em uso por um processo).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedItem {
+    pub path: String,
+    pub reason: String,
+}
+
+impl CleanReport {
+    /// Cria um relatório vazio.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registra um item removido.
+    pub fn record_removed(&mut self, path: String) {
+        self.removed.push(path);
+    }
+
+    /// Registra um diretório removido só em parte e o espaço liberado.
+    pub fn record_partial(&mut self, path: String, freed_bytes: u64) {
+        self.partial.push(PartialItem { path, freed_bytes });
+    }
+
+    /// Registra um item que não foi removido e o motivo.
+    pub fn record_skipped(&mut self, path: String, reason: String) {
+        self.skipped.push(SkippedItem { path, reason });
+    }
+
+    /// Marca a limpeza como cancelada.
+    pub fn mark_cancelled(&mut self) {
+        self.cancelled = true;
+    }
+
+    /// Incorpora o relatório de outra limpeza.
+    pub fn merge(&mut self, other: CleanReport) {
+        self.removed.extend(other.removed);
+        self.partial.extend(other.partial);
+        self.skipped.extend(other.skipped);
+        self.cancelled |= other.cancelled;
+    }
+
+    /// Retorna os caminhos dos itens removidos.
+    pub fn removed(&self) -> &[String] {
+        &self.removed
+    }
+
+    /// Retorna a quantidade de itens removidos.
+    pub fn removed_count(&self) -> usize {
+        self.removed.len()
+    }
+
+    /// Retorna os diretórios removidos só em parte.
+    pub fn partial(&self) -> &[PartialItem] {
+        &self.partial
+    }
+
+    /// Retorna os itens deixados de fora e os motivos.
+    pub fn skipped(&self) -> &[SkippedItem] {
+        &self.skipped
+    }
+
+    /// Verifica se a limpeza foi cancelada.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_merge_reports() {
+        let mut first = CleanReport::new();
+        first.record_removed("/tmp/a.log".to_string());
+
+        let mut second = CleanReport::new();
+        second.record_removed("/tmp/b.log".to_string());
+        second.record_skipped("/tmp/c.log".to_string(), "em uso".to_string());
+        second.record_partial("/tmp/build".to_string(), 4096);
+        second.mark_cancelled();
+
+        first.merge(second);
+        assert_eq!(first.removed(), ["/tmp/a.log", "/tmp/b.log"]);
+        assert_eq!(first.partial()[0].freed_bytes, 4096);
+        assert_eq!(first.skipped()[0].path, "/tmp/c.log");
+        assert!(first.is_cancelled());
+    }
+}
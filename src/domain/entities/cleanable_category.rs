@@ -1,11 +1,15 @@
 use super::cleanable_item::CleanableItem;
+use crate::domain::value_objects::{CategoryType, Truncation};
 
 /// Representa uma categoria de itens limpáveis.
 /// Agregado raiz que contém múltiplos CleanableItems.
 #[derive(Debug, Clone)]
 pub struct CleanableCategory {
     name: String,
+    category_type: Option<CategoryType>,
     items: Vec<CleanableItem>,
+    truncation: Option<Truncation>,
+    scan_error: Option<String>,
 }
 
 impl CleanableCategory {
@@ -13,15 +17,79 @@ impl CleanableCategory {
     pub fn new(name: String) -> Self {
         Self {
             name,
+            category_type: None,
             items: Vec::new(),
+            truncation: None,
+            scan_error: None,
         }
     }
 
+    /// Cria uma categoria vazia para um tipo conhecido.
+    pub fn for_type(category_type: CategoryType) -> Self {
+        Self {
+            category_type: Some(category_type),
+            ..Self::new(category_type.display_name().to_string())
+        }
+    }
+
+    /// Indica se os itens devem ser selecionados individualmente.
+    pub fn requires_review(&self) -> bool {
+        self.category_type.is_some_and(|category_type| category_type.requires_review())
+    }
+
+    /// Cria uma cópia da categoria contendo apenas os itens dos índices informados.
+    /// Índices inválidos são ignorados.
+    pub fn subset(&self, indices: &[usize]) -> Self {
+        Self {
+            name: self.name.clone(),
+            category_type: self.category_type,
+            items: indices.iter().filter_map(|&i| self.items.get(i).cloned()).collect(),
+            truncation: None,
+            scan_error: None,
+        }
+    }
+
+    /// Retorna o tipo da categoria, se conhecido.
+    pub fn category_type(&self) -> Option<CategoryType> {
+        self.category_type
+    }
+
+    /// Registra que parte dos itens não foi listada por causa de um limite.
+    pub fn mark_truncated(&mut self, truncation: Truncation) {
+        self.truncation = Some(truncation).filter(Truncation::is_truncated);
+    }
+
+    /// Retorna o que ficou de fora da categoria, se algo ficou.
+    pub fn truncation(&self) -> Option<Truncation> {
+        self.truncation
+    }
+
+    /// Registra que a varredura da categoria falhou, com o motivo.
+    pub fn mark_failed(&mut self, error: String) {
+        self.scan_error = Some(error);
+    }
+
+    /// Retorna o motivo da falha da varredura, se ela falhou.
+    pub fn scan_error(&self) -> Option<&str> {
+        self.scan_error.as_deref()
+    }
+
     /// Adiciona um item à categoria.
-    pub fn add_item(&mut self, item: CleanableItem) {
-        if item.is_significant() {
-            self.items.push(item);
+    /// Itens sem categoria de origem passam a apontar para esta.
+    pub fn add_item(&mut self, mut item: CleanableItem) {
+        if !item.is_significant() {
+            return;
         }
+
+        if let (None, Some(category_type)) = (item.category(), self.category_type) {
+            item = item.with_category(category_type);
+        }
+        self.items.push(item);
+    }
+
+    /// Remove os itens cujos caminhos constam da lista.
+    pub fn remove_items(&mut self, paths: &[String]) {
+        self.items.retain(|item| !paths.iter().any(|path| path == item.path()));
     }
 
     /// Retorna o nome da categoria.
@@ -34,9 +102,19 @@ impl CleanableCategory {
         &self.items
     }
 
-    /// Calcula o tamanho total da categoria.
+    /// Calcula o tamanho total que pode ser liberado na categoria.
     pub fn total_size(&self) -> u64 {
-        self.items.iter().map(|item| item.size_in_bytes()).sum()
+        self.items.iter().map(|item| item.reclaimable_bytes()).sum()
+    }
+
+    /// Soma o tamanho aparente dos itens que podem ser removidos.
+    pub fn total_apparent_size(&self) -> u64 {
+        self.items.iter().map(|item| item.reclaimable_apparent_bytes()).sum()
+    }
+
+    /// Quantidade de itens de tamanho desconhecido, que ficam fora dos totais.
+    pub fn unknown_size_count(&self) -> usize {
+        self.items.iter().filter(|item| !item.is_size_known()).count()
     }
 
     /// Retorna a quantidade de itens na categoria.
@@ -60,6 +138,7 @@ impl CleanableCategory {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::value_objects::ItemDetails;
 
     #[test]
     fn should_create_empty_category() {
@@ -79,6 +158,58 @@ mod tests {
         assert_eq!(category.total_size(), 3072);
     }
 
+    #[test]
+    fn should_remove_items_by_path() {
+        let mut category = CleanableCategory::new("Logs".to_string());
+        category.add_item(CleanableItem::new("/tmp/test1.log".to_string(), 1024));
+        category.add_item(CleanableItem::new("/tmp/test2.log".to_string(), 2048));
+
+        category.remove_items(&["/tmp/test1.log".to_string()]);
+
+        assert_eq!(category.item_count(), 1);
+        assert_eq!(category.items()[0].path(), "/tmp/test2.log");
+    }
+
+    #[test]
+    fn should_record_truncation_only_when_items_were_skipped() {
+        let mut category = CleanableCategory::new("Temp".to_string());
+
+        category.mark_truncated(Truncation::default());
+        assert!(category.truncation().is_none());
+
+        category.mark_truncated(Truncation::new(10, 4096));
+        assert_eq!(category.truncation().unwrap().skipped_items(), 10);
+    }
+
+    #[test]
+    fn should_exclude_kept_items_from_total() {
+        let mut category = CleanableCategory::for_type(CategoryType::DuplicateFiles);
+        category.add_item(CleanableItem::new("/data/a.iso".to_string(), 4096).with_details(ItemDetails::DuplicateOriginal));
+        category.add_item(
+            CleanableItem::new("/data/b.iso".to_string(), 4096)
+                .with_details(ItemDetails::DuplicateCopy { original: "/data/a.iso".to_string() }),
+        );
+
+        assert_eq!(category.category_type(), Some(CategoryType::DuplicateFiles));
+        assert_eq!(category.item_count(), 2);
+        assert_eq!(category.total_size(), 4096);
+    }
+
+    #[test]
+    fn should_build_subset_from_item_indices() {
+        let mut category = CleanableCategory::for_type(CategoryType::LargeFiles);
+        category.add_item(CleanableItem::new("/home/dev/a.iso".to_string(), 10));
+        category.add_item(CleanableItem::new("/home/dev/b.iso".to_string(), 20));
+        category.add_item(CleanableItem::new("/home/dev/c.iso".to_string(), 30));
+
+        let subset = category.subset(&[2, 0, 7]);
+
+        assert!(subset.requires_review());
+        assert_eq!(subset.item_count(), 2);
+        assert_eq!(subset.items()[0].path(), "/home/dev/c.iso");
+        assert_eq!(subset.total_size(), 40);
+    }
+
     #[test]
     fn should_ignore_insignificant_items() {
         let mut category = CleanableCategory::new("Empty".to_string());
@@ -1,9 +1,21 @@
+use crate::domain::value_objects::{CategoryType, Ecosystem, ItemDetails, ItemKind, LogState};
+use std::time::{Duration, SystemTime};
+
 /// Representa um item que pode ser limpo do sistema.
 /// Esta é uma entidade de domínio pura, sem dependências externas.
+/// O que é próprio de uma categoria fica em `details`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CleanableItem {
     path: String,
     size_in_bytes: u64,
+    apparent_size: u64,
+    size_known: bool,
+    kind: ItemKind,
+    modified: Option<SystemTime>,
+    accessed: Option<SystemTime>,
+    owner_uid: Option<u32>,
+    category: Option<CategoryType>,
+    details: ItemDetails,
 }
 
 impl CleanableItem {
@@ -11,28 +23,245 @@ impl CleanableItem {
     /// 
     /// # Argumentos
     /// * `path` - Caminho completo do item
-    /// * `size_in_bytes` - Tamanho em bytes
+    /// * `size_in_bytes` - Tamanho ocupado em disco, em bytes
     pub fn new(path: String, size_in_bytes: u64) -> Self {
         Self {
             path,
             size_in_bytes,
+            apparent_size: size_in_bytes,
+            size_known: true,
+            kind: ItemKind::File,
+            modified: None,
+            accessed: None,
+            owner_uid: None,
+            category: None,
+            details: ItemDetails::default(),
         }
     }
 
+    /// Define o tamanho aparente (soma dos `len`), que pode diferir do ocupado
+    /// em disco em arquivos esparsos, hardlinks e por causa dos blocos.
+    pub fn with_apparent_size(mut self, apparent_size: u64) -> Self {
+        self.apparent_size = apparent_size;
+        self
+    }
+
+    /// Define o tipo do item.
+    pub fn with_kind(mut self, kind: ItemKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Define as datas de modificação e de último acesso.
+    pub fn with_times(mut self, modified: Option<SystemTime>, accessed: Option<SystemTime>) -> Self {
+        self.modified = modified;
+        self.accessed = accessed;
+        self
+    }
+
+    /// Define o uid do dono do item.
+    pub fn with_owner(mut self, owner_uid: u32) -> Self {
+        self.owner_uid = Some(owner_uid);
+        self
+    }
+
+    /// Define a categoria de onde o item veio.
+    pub fn with_category(mut self, category: CategoryType) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Define os dados próprios da categoria do item.
+    pub fn with_details(mut self, details: ItemDetails) -> Self {
+        self.details = details;
+        self
+    }
+
+    /// Marca o tamanho como desconhecido: o item é listado, mas não entra nos totais.
+    pub fn with_unknown_size(mut self) -> Self {
+        self.size_in_bytes = 0;
+        self.apparent_size = 0;
+        self.size_known = false;
+        self
+    }
+
     pub fn path(&self) -> &str {
         &self.path
     }
 
+    /// Tamanho ocupado em disco (blocos alocados).
     pub fn size_in_bytes(&self) -> u64 {
         self.size_in_bytes
     }
 
-    /// Verifica se o item é significativo (> 0 bytes)
+    /// Indica se o tamanho foi medido. Sem medida, os tamanhos valem 0.
+    pub fn is_size_known(&self) -> bool {
+        self.size_known
+    }
+
+    /// Tamanho aparente, como mostrado por `ls`.
+    pub fn apparent_size(&self) -> u64 {
+        self.apparent_size
+    }
+
+    /// Verifica se o item pode ser removido.
+    /// O original de um grupo de duplicados é listado, mas nunca removido.
+    pub fn is_reclaimable(&self) -> bool {
+        self.details != ItemDetails::DuplicateOriginal
+    }
+
+    /// Tamanho aparente dos itens que podem ser removidos.
+    pub fn reclaimable_apparent_bytes(&self) -> u64 {
+        if self.is_reclaimable() {
+            self.apparent_size
+        } else {
+            0
+        }
+    }
+
+    /// Bytes liberados ao remover o item.
+    pub fn reclaimable_bytes(&self) -> u64 {
+        if self.is_reclaimable() {
+            self.size_in_bytes()
+        } else {
+            0
+        }
+    }
+
+    /// Caminho do original do qual este item é cópia, se houver.
+    pub fn original_path(&self) -> Option<&str> {
+        match &self.details {
+            ItemDetails::DuplicateCopy { original } => Some(original),
+            _ => None,
+        }
+    }
+
+    pub fn kind(&self) -> ItemKind {
+        self.kind
+    }
+
+    pub fn modified(&self) -> Option<SystemTime> {
+        self.modified
+    }
+
+    pub fn accessed(&self) -> Option<SystemTime> {
+        self.accessed
+    }
+
+    pub fn owner_uid(&self) -> Option<u32> {
+        self.owner_uid
+    }
+
+    pub fn category(&self) -> Option<CategoryType> {
+        self.category
+    }
+
+    /// Caminhos removidos na limpeza de um item composto.
+    pub fn parts(&self) -> &[String] {
+        match &self.details {
+            ItemDetails::BrowserProfile { parts } => parts,
+            _ => &[],
+        }
+    }
+
+    /// Diretório do projeto de um artefato de build.
+    pub fn project(&self) -> Option<&str> {
+        match &self.details {
+            ItemDetails::BuildArtifact { project, .. } => Some(project),
+            _ => None,
+        }
+    }
+
+    pub fn ecosystem(&self) -> Option<Ecosystem> {
+        match &self.details {
+            ItemDetails::BuildArtifact { ecosystem, .. } => Some(*ecosystem),
+            _ => None,
+        }
+    }
+
+    /// Tempo sem alterações no projeto do artefato, se conhecido.
+    pub fn project_idle_age(&self, now: SystemTime) -> Option<Duration> {
+        match &self.details {
+            ItemDetails::BuildArtifact { active_at, .. } => age(*active_at, now),
+            _ => None,
+        }
+    }
+
+    pub fn log_state(&self) -> Option<LogState> {
+        match &self.details {
+            ItemDetails::Log(state) => Some(*state),
+            _ => None,
+        }
+    }
+
+    /// Indica se a limpeza esvazia o arquivo em vez de removê-lo.
+    pub fn truncates_in_place(&self) -> bool {
+        self.details == ItemDetails::Log(LogState::Active)
+    }
+
+    /// ID completo do objeto Docker (ou nome, para volumes).
+    pub fn docker_id(&self) -> Option<&str> {
+        match &self.details {
+            ItemDetails::Docker { id } => Some(id),
+            _ => None,
+        }
+    }
+
+    /// Executável cuja falha gerou o dump.
+    pub fn crashed_exe(&self) -> Option<&str> {
+        match &self.details {
+            ItemDetails::Crash { exe, .. } => Some(exe),
+            _ => None,
+        }
+    }
+
+    /// Tempo desde a falha, se conhecido.
+    pub fn crash_age(&self, now: SystemTime) -> Option<Duration> {
+        match &self.details {
+            ItemDetails::Crash { at, .. } => age(*at, now),
+            _ => None,
+        }
+    }
+
+    /// Caminho original de um item da lixeira.
+    pub fn trashed_from(&self) -> Option<&str> {
+        match &self.details {
+            ItemDetails::Trashed { from, .. } => Some(from),
+            _ => None,
+        }
+    }
+
+    /// Tempo desde que o item foi para a lixeira, se conhecido.
+    pub fn trashed_age(&self, now: SystemTime) -> Option<Duration> {
+        match &self.details {
+            ItemDetails::Trashed { at, .. } => age(*at, now),
+            _ => None,
+        }
+    }
+
+    /// Tempo desde a última modificação, se conhecido.
+    pub fn modified_age(&self, now: SystemTime) -> Option<Duration> {
+        age(self.modified(), now)
+    }
+
+    /// Tempo desde o último acesso, se conhecido.
+    pub fn accessed_age(&self, now: SystemTime) -> Option<Duration> {
+        age(self.accessed(), now)
+    }
+
+    /// Verifica se o item é significativo (> 0 bytes em disco ou aparentes).
+    /// Um hardlink já contado ocupa 0 bytes, mas continua sendo listado.
+    /// Itens de tamanho desconhecido também são listados.
     pub fn is_significant(&self) -> bool {
-        self.size_in_bytes > 0
+        self.size_in_bytes > 0 || self.apparent_size > 0 || !self.size_known
     }
 }
 
+/// Tempo decorrido desde `time`; datas no futuro não têm idade.
+fn age(time: Option<SystemTime>, now: SystemTime) -> Option<Duration> {
+    time.and_then(|time| now.duration_since(time).ok())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -45,6 +274,107 @@ mod tests {
         assert!(item.is_significant());
     }
 
+    #[test]
+    fn should_not_count_kept_items_as_reclaimable() {
+        let original = CleanableItem::new("/data/a.iso".to_string(), 4096).with_details(ItemDetails::DuplicateOriginal);
+        let copy = CleanableItem::new("/data/b.iso".to_string(), 4096)
+            .with_details(ItemDetails::DuplicateCopy { original: "/data/a.iso".to_string() });
+
+        assert!(!original.is_reclaimable());
+        assert_eq!(original.reclaimable_bytes(), 0);
+        assert_eq!(copy.reclaimable_bytes(), 4096);
+        assert_eq!(copy.original_path(), Some("/data/a.iso"));
+    }
+
+    #[test]
+    fn should_carry_metadata() {
+        let now = SystemTime::now();
+        let day = Duration::from_secs(24 * 60 * 60);
+        let item = CleanableItem::new("/tmp/cache".to_string(), 4096)
+            .with_kind(ItemKind::Directory)
+            .with_times(Some(now - day * 3), Some(now - day))
+            .with_owner(1000)
+            .with_category(CategoryType::TemporaryFiles);
+
+        assert_eq!(item.kind(), ItemKind::Directory);
+        assert_eq!(item.owner_uid(), Some(1000));
+        assert_eq!(item.category(), Some(CategoryType::TemporaryFiles));
+        assert_eq!(item.modified_age(now), Some(day * 3));
+        assert_eq!(item.accessed_age(now), Some(day));
+    }
+
+    #[test]
+    fn should_carry_trash_origin() {
+        let now = SystemTime::now();
+        let day = Duration::from_secs(24 * 60 * 60);
+        let item = CleanableItem::new("/home/dev/.local/share/Trash/files/a.txt".to_string(), 10)
+            .with_details(ItemDetails::Trashed { from: "/home/dev/a.txt".to_string(), at: Some(now - day * 40) });
+
+        assert_eq!(item.trashed_from(), Some("/home/dev/a.txt"));
+        assert_eq!(item.trashed_age(now), Some(day * 40));
+        assert_eq!(CleanableItem::new("/tmp/a".to_string(), 1).trashed_age(now), None);
+    }
+
+    #[test]
+    fn should_carry_project_of_build_artifact() {
+        let now = SystemTime::now();
+        let day = Duration::from_secs(24 * 60 * 60);
+        let item = CleanableItem::new("/src/app/node_modules".to_string(), 4096)
+            .with_details(ItemDetails::BuildArtifact {
+                project: "/src/app".to_string(),
+                ecosystem: Ecosystem::Node,
+                active_at: Some(now - day * 20),
+            });
+
+        assert_eq!(item.project(), Some("/src/app"));
+        assert_eq!(item.ecosystem(), Some(Ecosystem::Node));
+        assert_eq!(item.project_idle_age(now), Some(day * 20));
+        assert_eq!(CleanableItem::new("/tmp/a".to_string(), 1).project(), None);
+    }
+
+    #[test]
+    fn should_carry_crash_origin() {
+        let now = SystemTime::now();
+        let day = Duration::from_secs(24 * 60 * 60);
+        let item = CleanableItem::new("/var/crash/app.crash".to_string(), 10)
+            .with_details(ItemDetails::Crash { exe: "/usr/bin/app".to_string(), at: Some(now - day * 2) });
+
+        assert_eq!(item.crashed_exe(), Some("/usr/bin/app"));
+        assert_eq!(item.crash_age(now), Some(day * 2));
+    }
+
+    #[test]
+    fn should_list_items_of_unknown_size_without_counting_them() {
+        let item = CleanableItem::new("Docker Volume: dados".to_string(), 100_000_000).with_unknown_size();
+
+        assert!(!item.is_size_known());
+        assert!(item.is_significant());
+        assert_eq!(item.reclaimable_bytes(), 0);
+        assert_eq!(item.reclaimable_apparent_bytes(), 0);
+    }
+
+    #[test]
+    fn should_truncate_only_active_logs() {
+        let active = CleanableItem::new("/var/log/app.log".to_string(), 10).with_details(ItemDetails::Log(LogState::Active));
+        let rotated = CleanableItem::new("/var/log/app.log.1".to_string(), 10).with_details(ItemDetails::Log(LogState::Rotated));
+
+        assert!(active.truncates_in_place());
+        assert!(!rotated.truncates_in_place());
+        assert!(!CleanableItem::new("/tmp/a".to_string(), 1).truncates_in_place());
+    }
+
+    #[test]
+    fn should_keep_apparent_size_apart_from_disk_usage() {
+        let sparse = CleanableItem::new("/tmp/disk.img".to_string(), 4096).with_apparent_size(1 << 30);
+        assert_eq!(sparse.size_in_bytes(), 4096);
+        assert_eq!(sparse.apparent_size(), 1 << 30);
+        assert_eq!(sparse.reclaimable_bytes(), 4096);
+
+        let extra_link = CleanableItem::new("/tmp/link".to_string(), 0).with_apparent_size(10);
+        assert!(extra_link.is_significant());
+        assert_eq!(extra_link.with_details(ItemDetails::DuplicateOriginal).reclaimable_apparent_bytes(), 0);
+    }
+
     #[test]
     fn should_identify_insignificant_item() {
         let item = CleanableItem::new("/tmp/empty.log".to_string(), 0);
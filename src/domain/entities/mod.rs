@@ -1,7 +1,9 @@
 pub mod cleanable_item;
 pub mod cleanable_category;
 pub mod scan_result;
+pub mod clean_report;
 
 pub use cleanable_item::CleanableItem;
 pub use cleanable_category::CleanableCategory;
 pub use scan_result::ScanResult;
+pub use clean_report::CleanReport;
@@ -37,11 +37,23 @@ impl ScanResult {
         self.categories.iter_mut().find(|c| c.name() == name)
     }
 
+    /// Remove de todas as categorias os itens já limpos.
+    pub fn remove_items(&mut self, paths: &[String]) {
+        for category in &mut self.categories {
+            category.remove_items(paths);
+        }
+    }
+
     /// Calcula o tamanho total de todos os itens encontrados.
     pub fn total_size(&self) -> u64 {
         self.categories.iter().map(|cat| cat.total_size()).sum()
     }
 
+    /// Soma o tamanho aparente de todas as categorias.
+    pub fn total_apparent_size(&self) -> u64 {
+        self.categories.iter().map(|cat| cat.total_apparent_size()).sum()
+    }
+
     /// Retorna o total de itens em todas as categorias.
     pub fn total_items(&self) -> usize {
         self.categories.iter().map(|cat| cat.item_count()).sum()
@@ -88,6 +100,7 @@ mod tests {
         
         assert_eq!(result.total_items(), 2);
         assert_eq!(result.total_size(), 3072);
+        assert_eq!(result.total_apparent_size(), 3072);
         assert!(result.has_items());
     }
 }
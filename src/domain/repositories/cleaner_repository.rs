@@ -1,11 +1,13 @@
-use crate::domain::entities::CleanableCategory;
+use crate::domain::entities::{CleanableCategory, CleanReport};
+use crate::domain::value_objects::CancellationToken;
 use anyhow::Result;
 
 /// Interface de repositório para operações de limpeza.
 /// Define o contrato que as implementações devem seguir (Dependency Inversion Principle).
 pub trait CleanerRepository: Send + Sync {
     /// Limpa os itens de uma categoria específica.
-    fn clean_category(&self, category: &CleanableCategory) -> Result<usize>;
+    /// Interrompe entre itens quando o token é cancelado.
+    fn clean_category(&self, category: &CleanableCategory, cancellation: &CancellationToken) -> Result<CleanReport>;
     
     /// Verifica se é possível limpar uma categoria.
     fn can_clean(&self, category: &CleanableCategory) -> bool;
@@ -1,5 +1,5 @@
 pub mod scanner_repository;
 pub mod cleaner_repository;
 
-pub use scanner_repository::ScannerRepository;
+pub use scanner_repository::{ScannerRepository, ScanProgressObserver, NoScanProgress};
 pub use cleaner_repository::CleanerRepository;
@@ -1,13 +1,32 @@
 use crate::domain::entities::ScanResult;
-use crate::domain::value_objects::CategoryType;
+use crate::domain::value_objects::{CategoryType, ScanProgress, CancellationToken};
 use anyhow::Result;
 
+/// Observador que recebe os eventos de progresso de uma varredura.
+/// Pode ser chamado a partir de várias threads ao mesmo tempo.
+pub trait ScanProgressObserver: Send + Sync {
+    fn on_progress(&self, progress: ScanProgress);
+}
+
+/// Observador que descarta todos os eventos.
+pub struct NoScanProgress;
+
+impl ScanProgressObserver for NoScanProgress {
+    fn on_progress(&self, _progress: ScanProgress) {}
+}
+
 /// Interface de repositório para operações de varredura.
 /// Define o contrato que as implementações devem seguir (Dependency Inversion Principle).
 pub trait ScannerRepository: Send + Sync {
-    /// Escaneia o sistema e retorna os resultados.
-    fn scan_system(&self) -> Result<ScanResult>;
+    /// Escaneia o sistema notificando o progresso ao observador.
+    /// Retorna erro quando o token é cancelado antes do fim.
+    fn scan_system_with_progress(
+        &self,
+        observer: &dyn ScanProgressObserver,
+        cancellation: &CancellationToken,
+    ) -> Result<ScanResult>;
     
     /// Escaneia uma categoria específica.
+    #[allow(dead_code)]
     fn scan_category(&self, category_type: CategoryType) -> Result<Vec<crate::domain::entities::CleanableItem>>;
 }
@@ -7,6 +7,16 @@ pub enum CategoryType {
     DockerImages,
     DockerVolumes,
     DevelopmentPackages,
+    DuplicateFiles,
+    LargeFiles,
+    Trash,
+    BrowserCaches,
+    RustArtifacts,
+    SystemJournal,
+    CrashDumps,
+    UnusedRuntimes,
+    DockerBuildCache,
+    DockerContainers,
 }
 
 impl CategoryType {
@@ -18,11 +28,20 @@ impl CategoryType {
             CategoryType::DockerImages => "🐳 Imagens Docker",
             CategoryType::DockerVolumes => "💾 Volumes Docker",
             CategoryType::DevelopmentPackages => "📦 Pacotes de Desenvolvimento",
+            CategoryType::DuplicateFiles => "👯 Arquivos Duplicados",
+            CategoryType::LargeFiles => "🐘 Arquivos Grandes e Antigos",
+            CategoryType::Trash => "🗑️ Lixeira",
+            CategoryType::BrowserCaches => "🌐 Cache de Navegadores",
+            CategoryType::RustArtifacts => "🦀 Artefatos Rust",
+            CategoryType::SystemJournal => "📰 Journal do systemd",
+            CategoryType::CrashDumps => "💥 Core Dumps e Relatórios de Falha",
+            CategoryType::UnusedRuntimes => "🧩 Runtimes Flatpak e Revisões Snap",
+            CategoryType::DockerBuildCache => "🧱 Cache de Build do Docker",
+            CategoryType::DockerContainers => "🚢 Contêineres Docker Parados",
         }
     }
 
     /// Retorna o identificador interno da categoria.
-    #[allow(dead_code)]
     pub fn identifier(&self) -> &'static str {
         match self {
             CategoryType::LogFiles => "log_files",
@@ -30,6 +49,16 @@ impl CategoryType {
             CategoryType::DockerImages => "docker_images",
             CategoryType::DockerVolumes => "docker_volumes",
             CategoryType::DevelopmentPackages => "dev_packages",
+            CategoryType::DuplicateFiles => "duplicate_files",
+            CategoryType::LargeFiles => "large_files",
+            CategoryType::Trash => "trash",
+            CategoryType::BrowserCaches => "browser_caches",
+            CategoryType::RustArtifacts => "rust_artifacts",
+            CategoryType::SystemJournal => "system_journal",
+            CategoryType::CrashDumps => "crash_dumps",
+            CategoryType::UnusedRuntimes => "unused_runtimes",
+            CategoryType::DockerBuildCache => "docker_build_cache",
+            CategoryType::DockerContainers => "docker_containers",
         }
     }
 
@@ -41,8 +70,25 @@ impl CategoryType {
             CategoryType::DockerImages,
             CategoryType::DockerVolumes,
             CategoryType::DevelopmentPackages,
+            CategoryType::DuplicateFiles,
+            CategoryType::LargeFiles,
+            CategoryType::Trash,
+            CategoryType::BrowserCaches,
+            CategoryType::RustArtifacts,
+            CategoryType::SystemJournal,
+            CategoryType::CrashDumps,
+            CategoryType::UnusedRuntimes,
+            CategoryType::DockerBuildCache,
+            CategoryType::DockerContainers,
         ]
     }
+
+    /// Indica se os itens precisam ser revisados e selecionados um a um.
+    /// Categorias assim nunca são limpas por inteiro.
+    /// Artefatos Rust misturam `target/` com o registry do cargo, que custa um novo download.
+    pub fn requires_review(&self) -> bool {
+        matches!(self, CategoryType::LargeFiles | CategoryType::RustArtifacts)
+    }
 }
 
 #[cfg(test)]
@@ -57,6 +103,13 @@ mod tests {
     #[test]
     fn should_return_all_categories() {
         let all = CategoryType::all();
-        assert_eq!(all.len(), 5);
+        assert_eq!(all.len(), 15);
+    }
+
+    #[test]
+    fn should_require_review_only_for_large_files_and_rust_artifacts() {
+        assert!(CategoryType::LargeFiles.requires_review());
+        assert!(CategoryType::RustArtifacts.requires_review());
+        assert!(!CategoryType::TemporaryFiles.requires_review());
     }
 }
@@ -0,0 +1,33 @@
+/// Value Object que representa o ecossistema de um projeto de software.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Ecosystem {
+    Node,
+    Gradle,
+    Python,
+    Php,
+    Rust,
+}
+
+impl Ecosystem {
+    /// Retorna o nome amigável do ecossistema.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Ecosystem::Node => "Node.js",
+            Ecosystem::Gradle => "Gradle",
+            Ecosystem::Python => "Python",
+            Ecosystem::Php => "PHP",
+            Ecosystem::Rust => "Rust",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_have_display_names() {
+        assert_eq!(Ecosystem::Node.display_name(), "Node.js");
+        assert_eq!(Ecosystem::Php.display_name(), "PHP");
+    }
+}
@@ -0,0 +1,47 @@
+use super::ecosystem::Ecosystem;
+use super::log_state::LogState;
+use std::time::SystemTime;
+
+/// Value Object com os dados que só fazem sentido para alguns itens.
+/// Cada variante pertence a uma categoria, então um item nunca mistura,
+/// por exemplo, a origem na lixeira com o projeto de um artefato.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ItemDetails {
+    /// Só os dados comuns: caminho, tamanho, tipo, datas e dono.
+    #[default]
+    Plain,
+    /// Arquivo de log e sua situação.
+    Log(LogState),
+    /// Artefato de build regenerável e o projeto ao qual pertence.
+    BuildArtifact {
+        project: String,
+        ecosystem: Ecosystem,
+        /// Última alteração das fontes ou do lockfile do projeto.
+        active_at: Option<SystemTime>,
+    },
+    /// Arquivo mantido de um grupo de duplicados: listado, mas nunca removido.
+    DuplicateOriginal,
+    /// Cópia de um arquivo que será mantido.
+    DuplicateCopy {
+        original: String,
+    },
+    /// Item da lixeira, com o caminho de onde saiu e quando.
+    Trashed {
+        from: String,
+        at: Option<SystemTime>,
+    },
+    /// Caches de um perfil de navegador: o item tem um rótulo como caminho
+    /// e a limpeza remove cada parte.
+    BrowserProfile {
+        parts: Vec<String>,
+    },
+    /// Core dump ou relatório de falha, com o executável que falhou e quando.
+    Crash {
+        exe: String,
+        at: Option<SystemTime>,
+    },
+    /// Objeto Docker, pelo ID completo (ou nome, para volumes) usado na remoção.
+    Docker {
+        id: String,
+    },
+}
@@ -0,0 +1,56 @@
+/// Value Object que representa o tipo de um item limpável.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemKind {
+    File,
+    Directory,
+    /// Link simbólico: a limpeza remove o link, nunca o alvo.
+    Symlink,
+    DockerImage,
+    DockerVolume,
+    /// Registro do cache de build do BuildKit.
+    DockerBuildCache,
+    /// Contêiner parado; a limpeza remove a camada gravável.
+    DockerContainer,
+    /// Journal do systemd, reduzido pelo `journalctl --vacuum-*`.
+    SystemJournal,
+    /// Runtime Flatpak sem aplicativo que o use, removido pelo `flatpak uninstall`.
+    FlatpakRuntime,
+    /// Revisão Snap desativada, removida pelo `snap remove --revision`.
+    SnapRevision,
+}
+
+impl ItemKind {
+    /// Retorna o nome amigável do tipo.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ItemKind::File => "Arquivo",
+            ItemKind::Directory => "Diretório",
+            ItemKind::Symlink => "Link simbólico",
+            ItemKind::DockerImage => "Imagem Docker",
+            ItemKind::DockerVolume => "Volume Docker",
+            ItemKind::DockerBuildCache => "Cache de build Docker",
+            ItemKind::DockerContainer => "Contêiner Docker parado",
+            ItemKind::SystemJournal => "Journal do systemd",
+            ItemKind::FlatpakRuntime => "Runtime Flatpak sem uso",
+            ItemKind::SnapRevision => "Revisão Snap desativada",
+        }
+    }
+
+    /// Indica se o item é uma entrada do sistema de arquivos.
+    pub fn is_filesystem(&self) -> bool {
+        matches!(self, ItemKind::File | ItemKind::Directory | ItemKind::Symlink)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_identify_filesystem_kinds() {
+        assert!(ItemKind::File.is_filesystem());
+        assert!(ItemKind::Directory.is_filesystem());
+        assert!(ItemKind::Symlink.is_filesystem());
+        assert!(!ItemKind::DockerImage.is_filesystem());
+    }
+}
@@ -0,0 +1,22 @@
+/// Value Object que representa a situação de um arquivo de log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogState {
+    /// Ainda em uso por um processo: remover não libera espaço até o
+    /// processo reiniciar, então a limpeza trunca o arquivo no lugar.
+    Active,
+    /// Arquivo antigo gerado pela rotação (`.1`, `.gz`, sufixo de data).
+    Rotated,
+    /// Log que nenhum processo usa nem escreve há algum tempo.
+    Orphaned,
+}
+
+impl LogState {
+    /// Retorna o nome amigável da situação.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            LogState::Active => "Log ativo (será truncado)",
+            LogState::Rotated => "Log rotacionado",
+            LogState::Orphaned => "Log órfão",
+        }
+    }
+}
@@ -1,3 +1,17 @@
 pub mod category_type;
+pub mod scan_progress;
+pub mod cancellation_token;
+pub mod truncation;
+pub mod item_kind;
+pub mod ecosystem;
+pub mod log_state;
+pub mod item_details;
 
 pub use category_type::CategoryType;
+pub use scan_progress::ScanProgress;
+pub use cancellation_token::CancellationToken;
+pub use truncation::Truncation;
+pub use item_kind::ItemKind;
+pub use ecosystem::Ecosystem;
+pub use log_state::LogState;
+pub use item_details::ItemDetails;
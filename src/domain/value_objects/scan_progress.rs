@@ -0,0 +1,54 @@
+use super::category_type::CategoryType;
+
+/// Value Object que descreve um evento de progresso da varredura.
+/// Os totais são acumulados sobre todas as categorias já escaneadas.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScanProgress {
+    /// A varredura de uma categoria começou.
+    CategoryStarted {
+        category: CategoryType,
+    },
+    /// Um diretório está sendo percorrido.
+    DirectoryEntered {
+        category: CategoryType,
+        path: String,
+    },
+    /// Um item limpável foi encontrado.
+    ItemFound {
+        category: CategoryType,
+        total_bytes: u64,
+        total_items: usize,
+    },
+    /// A varredura de uma categoria terminou.
+    CategoryFinished {
+        category: CategoryType,
+        completed_categories: usize,
+        total_categories: usize,
+    },
+}
+
+impl ScanProgress {
+    /// Retorna a categoria à qual o evento se refere.
+    pub fn category(&self) -> CategoryType {
+        match self {
+            ScanProgress::CategoryStarted { category }
+            | ScanProgress::DirectoryEntered { category, .. }
+            | ScanProgress::ItemFound { category, .. }
+            | ScanProgress::CategoryFinished { category, .. } => *category,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_expose_event_category() {
+        let event = ScanProgress::DirectoryEntered {
+            category: CategoryType::LogFiles,
+            path: "/var/log".to_string(),
+        };
+        assert_eq!(event.category(), CategoryType::LogFiles);
+    }
+}
@@ -0,0 +1,42 @@
+/// Value Object que descreve o que ficou de fora de uma categoria
+/// por causa de um limite configurado.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Truncation {
+    skipped_items: usize,
+    estimated_skipped_bytes: u64,
+}
+
+impl Truncation {
+    pub fn new(skipped_items: usize, estimated_skipped_bytes: u64) -> Self {
+        Self {
+            skipped_items,
+            estimated_skipped_bytes,
+        }
+    }
+
+    /// Quantidade de itens não listados.
+    pub fn skipped_items(&self) -> usize {
+        self.skipped_items
+    }
+
+    /// Estimativa do tamanho dos itens não listados.
+    pub fn estimated_skipped_bytes(&self) -> u64 {
+        self.estimated_skipped_bytes
+    }
+
+    /// Verifica se algum item foi deixado de fora.
+    pub fn is_truncated(&self) -> bool {
+        self.skipped_items > 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_only_be_truncated_when_items_were_skipped() {
+        assert!(!Truncation::default().is_truncated());
+        assert!(Truncation::new(3, 1024).is_truncated());
+    }
+}
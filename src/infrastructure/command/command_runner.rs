@@ -0,0 +1,93 @@
+use anyhow::{Context, Result};
+use std::ffi::OsString;
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+
+/// Saída de um programa externo já encerrado.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandOutput {
+    pub success: bool,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Executor de programas externos (journalctl, coredumpctl, etc).
+/// Permite trocar as ferramentas do sistema nos testes.
+pub trait CommandRunner: Send + Sync {
+    /// Executa `program` com `args` e espera o término.
+    /// Falha só se o programa não puder ser iniciado (ex.: não instalado).
+    fn run(&self, program: &str, args: &[&str]) -> Result<CommandOutput>;
+}
+
+/// Executa os programas do sistema, procurados no `PATH`.
+#[derive(Debug, Clone, Default)]
+pub struct SystemCommandRunner {
+    search_path: Option<OsString>,
+}
+
+impl SystemCommandRunner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Procura os programas em `search_path` em vez do `PATH` do processo.
+    #[allow(dead_code)]
+    pub fn with_search_path(search_path: impl Into<OsString>) -> Self {
+        Self { search_path: Some(search_path.into()) }
+    }
+}
+
+impl CommandRunner for SystemCommandRunner {
+    fn run(&self, program: &str, args: &[&str]) -> Result<CommandOutput> {
+        // Sem terminal, nenhum programa fica esperando uma confirmação
+        let mut command = Command::new(program);
+        command.args(args).stdin(Stdio::null());
+        if let Some(search_path) = &self.search_path {
+            command.env("PATH", search_path);
+        }
+
+        let output = command
+            .output()
+            .with_context(|| format!("Não foi possível executar {}", program))?;
+
+        Ok(CommandOutput {
+            success: output.status.success(),
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        })
+    }
+}
+
+/// Retorna o executor padrão, que usa o `PATH` do processo.
+pub fn default_command_runner() -> Arc<dyn CommandRunner> {
+    Arc::new(SystemCommandRunner::new())
+}
+
+/// Cria um programa falso (script de shell) em `dir` para os testes.
+#[cfg(test)]
+pub(crate) fn fake_program(dir: &std::path::Path, name: &str, script: &str) {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = dir.join(name);
+    std::fs::write(&path, format!("#!/bin/sh\n{}\n", script)).unwrap();
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_run_programs_from_search_path() {
+        let dir = tempfile::tempdir().unwrap();
+        fake_program(dir.path(), "ferramenta", "echo \"args: $*\"; echo aviso >&2; exit 3");
+
+        let runner = SystemCommandRunner::with_search_path(dir.path());
+        let output = runner.run("ferramenta", &["--disk-usage", "-q"]).unwrap();
+
+        assert!(!output.success);
+        assert_eq!(output.stdout, "args: --disk-usage -q\n");
+        assert_eq!(output.stderr, "aviso\n");
+        assert!(runner.run("nao-instalado", &[]).is_err());
+    }
+}
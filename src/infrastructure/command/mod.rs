@@ -0,0 +1,3 @@
+pub mod command_runner;
+
+pub use command_runner::{CommandRunner, default_command_runner};
@@ -0,0 +1,454 @@
+use crate::domain::value_objects::CategoryType;
+use super::exclusion_rules::ExclusionRules;
+use anyhow::{Result, anyhow, Context};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Versão atual do formato do arquivo de configuração.
+pub const CONFIG_VERSION: u32 = 1;
+
+/// Nome do arquivo de configuração dentro de `$XDG_CONFIG_HOME/pcclean/`.
+const CONFIG_FILE_NAME: &str = "config.toml";
+
+/// Configuração do usuário carregada de `$XDG_CONFIG_HOME/pcclean/config.toml`.
+/// Campos ausentes assumem os valores padrão.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AppConfig {
+    pub version: u32,
+    pub scan: ScanConfig,
+    pub exclusions: ExclusionsConfig,
+}
+
+/// Caminhos protegidos da varredura e da limpeza.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ExclusionsConfig {
+    /// Padrões no estilo `.gitignore` (ver `ExclusionRules`).
+    pub patterns: Vec<String>,
+}
+
+/// Configuração da varredura.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScanConfig {
+    /// Diretórios onde os projetos do usuário ficam (aceita `~`).
+    pub roots: Vec<String>,
+    /// Identificadores das categorias habilitadas (ver `CategoryType::identifier`).
+    pub enabled_categories: Vec<String>,
+    pub depth: DepthConfig,
+    pub limits: LimitsConfig,
+    pub duplicates: DuplicatesConfig,
+    pub large_files: LargeFilesConfig,
+    pub logs: LogsConfig,
+    pub dev_packages: DevPackagesConfig,
+    pub trash: TrashConfig,
+    pub rust: RustConfig,
+    pub journal: JournalConfig,
+    pub crash_dumps: CrashDumpsConfig,
+    /// Não atravessa pontos de montagem (ex.: shares NFS) abaixo das raízes.
+    pub same_file_system: bool,
+}
+
+/// Profundidade máxima de cada varredura.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DepthConfig {
+    pub log_files: usize,
+    pub temp_files: usize,
+    pub dev_packages: usize,
+    pub directory_size: usize,
+    pub duplicate_files: usize,
+    pub large_files: usize,
+    pub rust_targets: usize,
+    /// Profundidade das fontes de um projeto ao medir sua última alteração.
+    pub project_sources: usize,
+    /// Profundidade da busca por arquivos `core` soltos nas raízes.
+    pub core_files: usize,
+}
+
+/// Critérios da busca por arquivos duplicados.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DuplicatesConfig {
+    /// Arquivos menores que isso não são comparados.
+    pub min_size_bytes: u64,
+}
+
+/// Data usada para medir a idade de um arquivo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AgeBasis {
+    Accessed,
+    Modified,
+}
+
+/// Critérios da busca por arquivos grandes e antigos no home do usuário.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LargeFilesConfig {
+    pub min_size_bytes: u64,
+    pub min_age_days: u64,
+    pub age_basis: AgeBasis,
+}
+
+/// Critérios dos arquivos de log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogsConfig {
+    /// Um log fechado e sem escrita há esse número de dias é considerado órfão.
+    pub orphan_after_days: u64,
+    /// Também lista logs ativos, que são truncados no lugar em vez de removidos.
+    pub truncate_active: bool,
+}
+
+/// Critérios dos artefatos de build de projetos.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DevPackagesConfig {
+    /// Só lista artefatos de projetos cujas fontes e lockfile estão sem
+    /// alterações há pelo menos esse número de dias (0 = todos).
+    pub stale_project_days: u64,
+}
+
+/// Critérios da lixeira.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TrashConfig {
+    /// Só lista itens enviados à lixeira há pelo menos esse número de dias (0 = todos).
+    pub min_age_days: u64,
+}
+
+/// Critérios dos artefatos Rust.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RustConfig {
+    /// Só lista diretórios `target/` sem build há pelo menos esse número de dias (0 = todos).
+    pub target_min_age_days: u64,
+}
+
+/// Limites aplicados pelo `journalctl --vacuum-*` ao limpar o journal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct JournalConfig {
+    /// Mantém no máximo esse tamanho de journal arquivado (0 = sem limite de tamanho).
+    pub vacuum_size_mb: u64,
+    /// Remove journais arquivados mais antigos que esse número de dias (0 = sem limite de tempo).
+    pub vacuum_time_days: u64,
+}
+
+/// Critérios dos core dumps e relatórios de falha.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CrashDumpsConfig {
+    /// Só lista falhas de pelo menos esse número de dias atrás (0 = todas).
+    pub min_age_days: u64,
+}
+
+/// Limites opcionais de itens por categoria.
+/// Sem limite, a enumeração é completa.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LimitsConfig {
+    pub temp_files_per_dir: Option<usize>,
+    pub dev_packages_per_root: Option<usize>,
+}
+
+impl AppConfig {
+    /// Retorna o caminho do arquivo de configuração do usuário.
+    pub fn default_path() -> Option<PathBuf> {
+        let config_home = std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+        Some(config_home.join("pcclean").join(CONFIG_FILE_NAME))
+    }
+
+    /// Carrega a configuração do usuário ou os padrões se o arquivo não existir.
+    pub fn load() -> Result<Self> {
+        match Self::default_path() {
+            Some(path) if path.exists() => Self::load_from(&path),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    /// Carrega a configuração de um arquivo específico.
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Não foi possível ler {}", path.display()))?;
+        Self::parse(&content).with_context(|| format!("Configuração inválida em {}", path.display()))
+    }
+
+    /// Interpreta o conteúdo TOML da configuração.
+    pub fn parse(content: &str) -> Result<Self> {
+        let config: Self = toml::from_str(content)?;
+
+        if config.version == 0 || config.version > CONFIG_VERSION {
+            return Err(anyhow!(
+                "Versão de configuração não suportada: {} (suportada: {})",
+                config.version,
+                CONFIG_VERSION
+            ));
+        }
+
+        // Padrões inválidos são rejeitados aqui em vez de ignorados na limpeza
+        config.exclusions.rules(Path::new("/"))?;
+
+        Ok(config)
+    }
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            version: CONFIG_VERSION,
+            scan: ScanConfig::default(),
+            exclusions: ExclusionsConfig::default(),
+        }
+    }
+}
+
+impl ExclusionsConfig {
+    /// Compila os padrões configurados com `~` expandido para `home`.
+    pub fn rules(&self, home: &Path) -> Result<ExclusionRules> {
+        ExclusionRules::new(&self.patterns, home)
+    }
+}
+
+impl ScanConfig {
+    /// Retorna os diretórios raiz com `~` expandido para `home`.
+    pub fn resolved_roots(&self, home: &Path) -> Vec<PathBuf> {
+        self.roots.iter().map(|root| expand_home(root, home)).collect()
+    }
+
+    /// Verifica se uma categoria está habilitada.
+    pub fn is_enabled(&self, category_type: CategoryType) -> bool {
+        self.enabled_categories
+            .iter()
+            .any(|id| id == category_type.identifier())
+    }
+}
+
+impl Default for ScanConfig {
+    fn default() -> Self {
+        Self {
+            roots: vec![
+                "~/Projects".to_string(),
+                "~/Documents".to_string(),
+                "~/Desktop".to_string(),
+            ],
+            enabled_categories: CategoryType::all()
+                .iter()
+                .map(|category_type| category_type.identifier().to_string())
+                .collect(),
+            depth: DepthConfig::default(),
+            limits: LimitsConfig::default(),
+            duplicates: DuplicatesConfig::default(),
+            large_files: LargeFilesConfig::default(),
+            logs: LogsConfig::default(),
+            dev_packages: DevPackagesConfig::default(),
+            trash: TrashConfig::default(),
+            rust: RustConfig::default(),
+            journal: JournalConfig::default(),
+            crash_dumps: CrashDumpsConfig::default(),
+            same_file_system: true,
+        }
+    }
+}
+
+impl Default for DepthConfig {
+    fn default() -> Self {
+        Self {
+            log_files: 3,
+            temp_files: 2,
+            dev_packages: 4,
+            directory_size: 10,
+            duplicate_files: 10,
+            large_files: 10,
+            rust_targets: 6,
+            project_sources: 8,
+            core_files: 6,
+        }
+    }
+}
+
+impl Default for LargeFilesConfig {
+    fn default() -> Self {
+        Self {
+            min_size_bytes: 500 * 1024 * 1024,
+            min_age_days: 180,
+            age_basis: AgeBasis::Accessed,
+        }
+    }
+}
+
+impl Default for LogsConfig {
+    fn default() -> Self {
+        Self {
+            orphan_after_days: 7,
+            truncate_active: false,
+        }
+    }
+}
+
+impl Default for JournalConfig {
+    fn default() -> Self {
+        Self {
+            vacuum_size_mb: 500,
+            vacuum_time_days: 0,
+        }
+    }
+}
+
+impl Default for DuplicatesConfig {
+    fn default() -> Self {
+        Self {
+            min_size_bytes: 1024 * 1024,
+        }
+    }
+}
+
+/// Expande um `~` inicial para o diretório home.
+pub(super) fn expand_home(path: &str, home: &Path) -> PathBuf {
+    if path == "~" {
+        home.to_path_buf()
+    } else if let Some(rest) = path.strip_prefix("~/") {
+        home.join(rest)
+    } else {
+        PathBuf::from(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_use_defaults_for_missing_fields() {
+        let config = AppConfig::parse("version = 1\n").unwrap();
+        assert_eq!(config, AppConfig::default());
+    }
+
+    #[test]
+    fn should_parse_roots_depth_and_limits() {
+        let config = AppConfig::parse(
+            r#"
+            version = 1
+
+            [scan]
+            roots = ["~/src", "~/work"]
+            enabled_categories = ["log_files", "dev_packages"]
+
+            [scan.depth]
+            dev_packages = 6
+
+            [scan.limits]
+            dev_packages_per_root = 50
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.scan.resolved_roots(Path::new("/home/dev")),
+            vec![PathBuf::from("/home/dev/src"), PathBuf::from("/home/dev/work")]
+        );
+        assert_eq!(config.scan.depth.dev_packages, 6);
+        assert_eq!(config.scan.depth.log_files, 3);
+        assert_eq!(config.scan.limits.dev_packages_per_root, Some(50));
+        assert_eq!(config.scan.limits.temp_files_per_dir, None);
+        assert!(config.scan.is_enabled(CategoryType::DevelopmentPackages));
+        assert!(!config.scan.is_enabled(CategoryType::DockerImages));
+        assert!(config.scan.same_file_system);
+    }
+
+    #[test]
+    fn should_parse_large_files_age_basis() {
+        let config = AppConfig::parse(
+            "version = 1\n[scan.large_files]\nmin_age_days = 30\nage_basis = \"modified\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.scan.large_files.age_basis, AgeBasis::Modified);
+        assert_eq!(config.scan.large_files.min_age_days, 30);
+    }
+
+    #[test]
+    fn should_parse_trash_age() {
+        let config = AppConfig::parse("version = 1\n[scan.trash]\nmin_age_days = 30\n").unwrap();
+        assert_eq!(config.scan.trash.min_age_days, 30);
+        assert_eq!(AppConfig::default().scan.trash.min_age_days, 0);
+    }
+
+    #[test]
+    fn should_parse_rust_target_age() {
+        let config = AppConfig::parse(
+            "version = 1\n[scan.rust]\ntarget_min_age_days = 14\n[scan.depth]\nrust_targets = 3\n",
+        )
+        .unwrap();
+        assert_eq!(config.scan.rust.target_min_age_days, 14);
+        assert_eq!(config.scan.depth.rust_targets, 3);
+    }
+
+    #[test]
+    fn should_parse_stale_project_age() {
+        let config = AppConfig::parse("version = 1\n[scan.dev_packages]\nstale_project_days = 21\n").unwrap();
+        assert_eq!(config.scan.dev_packages.stale_project_days, 21);
+        assert_eq!(AppConfig::default().scan.dev_packages.stale_project_days, 0);
+    }
+
+    #[test]
+    fn should_parse_journal_vacuum_limits() {
+        let config = AppConfig::parse("version = 1\n[scan.journal]\nvacuum_time_days = 30\n").unwrap();
+        assert_eq!(config.scan.journal.vacuum_time_days, 30);
+        assert_eq!(config.scan.journal.vacuum_size_mb, 500);
+    }
+
+    #[test]
+    fn should_parse_log_policy() {
+        let config = AppConfig::parse("version = 1\n[scan.logs]\ntruncate_active = true\n").unwrap();
+        assert!(config.scan.logs.truncate_active);
+        assert_eq!(config.scan.logs.orphan_after_days, 7);
+        assert!(!AppConfig::default().scan.logs.truncate_active);
+    }
+
+    #[test]
+    fn should_parse_crash_dump_age() {
+        let config = AppConfig::parse("version = 1\n[scan.crash_dumps]\nmin_age_days = 3\n[scan.depth]\ncore_files = 2\n").unwrap();
+        assert_eq!(config.scan.crash_dumps.min_age_days, 3);
+        assert_eq!(config.scan.depth.core_files, 2);
+    }
+
+    #[test]
+    fn should_reject_unsupported_version() {
+        assert!(AppConfig::parse("version = 99\n").is_err());
+    }
+
+    #[test]
+    fn should_reject_unknown_fields() {
+        assert!(AppConfig::parse("version = 1\n[scan]\nroot = []\n").is_err());
+    }
+
+    #[test]
+    fn should_parse_exclusion_patterns() {
+        let config = AppConfig::parse(
+            "version = 1\n[exclusions]\npatterns = [\"*.log/\", \"~/keep\"]\n",
+        )
+        .unwrap();
+
+        let rules = config.exclusions.rules(Path::new("/home/dev")).unwrap();
+        assert!(rules.is_excluded(Path::new("/home/dev/keep/a.txt")));
+        assert!(AppConfig::parse("version = 1\n[exclusions]\npatterns = [\"[a\"]\n").is_err());
+    }
+
+    #[test]
+    fn should_load_config_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(CONFIG_FILE_NAME);
+        fs::write(&path, "version = 1\n[scan]\nroots = [\"/srv/code\"]\n").unwrap();
+
+        let config = AppConfig::load_from(&path).unwrap();
+        assert_eq!(config.scan.roots, vec!["/srv/code".to_string()]);
+    }
+}
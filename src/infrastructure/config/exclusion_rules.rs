@@ -0,0 +1,151 @@
+use super::app_config::expand_home;
+use anyhow::{Context, Result};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use std::path::Path;
+
+/// Arquivo marcador que protege o diretório onde está e tudo abaixo dele.
+pub const IGNORE_MARKER: &str = ".pccleanignore";
+
+/// Regras de exclusão no estilo `.gitignore`.
+///
+/// - `*.tmp` ou `cache/build` valem em qualquer profundidade;
+/// - `/srv/dados` ou `~/fixtures` são ancorados no caminho absoluto;
+/// - uma `/` final (`*.log/`) restringe o padrão a diretórios.
+///
+/// Um caminho é excluído quando ele ou qualquer diretório acima dele casa
+/// com um padrão ou contém o arquivo `.pccleanignore`.
+#[derive(Debug, Clone)]
+pub struct ExclusionRules {
+    any: GlobSet,
+    directories: GlobSet,
+}
+
+impl ExclusionRules {
+    /// Compila os padrões, expandindo `~` para `home`.
+    /// Linhas vazias e comentários (`#`) são ignorados.
+    pub fn new(patterns: &[String], home: &Path) -> Result<Self> {
+        let mut any = GlobSetBuilder::new();
+        let mut directories = GlobSetBuilder::new();
+
+        for pattern in patterns.iter().map(|pattern| pattern.trim()) {
+            if pattern.is_empty() || pattern.starts_with('#') {
+                continue;
+            }
+
+            let (pattern, directory_only) = match pattern.strip_suffix('/') {
+                Some(pattern) => (pattern, true),
+                None => (pattern, false),
+            };
+
+            let anchored = if pattern.starts_with('/') || pattern.starts_with('~') {
+                expand_home(pattern, home).to_string_lossy().to_string()
+            } else {
+                format!("**/{}", pattern)
+            };
+
+            let glob = GlobBuilder::new(&anchored)
+                .literal_separator(true)
+                .build()
+                .with_context(|| format!("Padrão de exclusão inválido: {}", pattern))?;
+
+            if directory_only {
+                directories.add(glob);
+            } else {
+                any.add(glob);
+            }
+        }
+
+        Ok(Self {
+            any: any.build()?,
+            directories: directories.build()?,
+        })
+    }
+
+    /// Verifica somente o próprio caminho, sem olhar os diretórios acima.
+    /// Usado pelos percursos que já podam os diretórios excluídos.
+    pub fn matches(&self, path: &Path, is_dir: bool) -> bool {
+        self.any.is_match(path)
+            || (is_dir && (self.directories.is_match(path) || path.join(IGNORE_MARKER).exists()))
+    }
+
+    /// Verifica o caminho e todos os diretórios acima dele.
+    pub fn is_excluded(&self, path: &Path) -> bool {
+        let is_dir = path.symlink_metadata().is_ok_and(|metadata| metadata.is_dir());
+
+        self.matches(path, is_dir)
+            || path.ancestors().skip(1).any(|ancestor| self.matches(ancestor, true))
+    }
+}
+
+impl Default for ExclusionRules {
+    fn default() -> Self {
+        Self {
+            any: GlobSet::empty(),
+            directories: GlobSet::empty(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn rules(patterns: &[&str]) -> ExclusionRules {
+        let patterns: Vec<String> = patterns.iter().map(|p| p.to_string()).collect();
+        ExclusionRules::new(&patterns, Path::new("/home/dev")).unwrap()
+    }
+
+    #[test]
+    fn should_match_unanchored_patterns_at_any_depth() {
+        let rules = rules(&["*.iso", "vendor/cache"]);
+
+        assert!(rules.is_excluded(Path::new("/data/images/debian.iso")));
+        assert!(rules.is_excluded(Path::new("/srv/app/vendor/cache/pkg.tar")));
+        assert!(!rules.is_excluded(Path::new("/srv/app/vendor/pkg.tar")));
+    }
+
+    #[test]
+    fn should_anchor_absolute_and_home_patterns() {
+        let rules = rules(&["~/keep", "/srv/data/**"]);
+
+        assert!(rules.is_excluded(Path::new("/home/dev/keep/notes.txt")));
+        assert!(!rules.is_excluded(Path::new("/home/other/keep/notes.txt")));
+        assert!(rules.is_excluded(Path::new("/srv/data/a/b.bin")));
+    }
+
+    #[test]
+    fn should_apply_trailing_slash_patterns_only_to_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        let fixtures = dir.path().join("fixtures.log");
+        fs::create_dir_all(&fixtures).unwrap();
+        fs::write(fixtures.join("sample.txt"), b"x").unwrap();
+        fs::write(dir.path().join("app.log"), b"x").unwrap();
+
+        let rules = rules(&["*.log/"]);
+
+        assert!(rules.is_excluded(&fixtures));
+        assert!(rules.is_excluded(&fixtures.join("sample.txt")));
+        assert!(!rules.is_excluded(&dir.path().join("app.log")));
+    }
+
+    #[test]
+    fn should_shield_tree_containing_marker_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let shielded = dir.path().join("projeto");
+        fs::create_dir_all(shielded.join("build")).unwrap();
+        fs::write(shielded.join(IGNORE_MARKER), b"").unwrap();
+
+        let rules = ExclusionRules::default();
+
+        assert!(rules.is_excluded(&shielded));
+        assert!(rules.is_excluded(&shielded.join("build/out.o")));
+        assert!(!rules.is_excluded(&dir.path().join("outro")));
+    }
+
+    #[test]
+    fn should_reject_invalid_patterns() {
+        let patterns = vec!["[abc".to_string()];
+        assert!(ExclusionRules::new(&patterns, Path::new("/home/dev")).is_err());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod app_config;
+pub mod exclusion_rules;
+
+pub use app_config::{AppConfig, ScanConfig, AgeBasis, JournalConfig};
+pub use exclusion_rules::ExclusionRules;
@@ -0,0 +1,560 @@
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Socket padrão do Docker Engine.
+const DEFAULT_DOCKER_HOST: &str = "unix:///var/run/docker.sock";
+
+/// Tempo máximo de espera por uma resposta; um prune pode demorar.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Filtro das imagens e volumes sem uso.
+const DANGLING_FILTER: &str = r#"{"dangling":["true"]}"#;
+
+/// Filtro dos contêineres parados.
+const STOPPED_FILTER: &str = r#"{"status":["exited","dead"]}"#;
+
+/// Falhas ao falar com o Docker Engine.
+#[derive(Debug)]
+pub enum DockerError {
+    /// `DOCKER_HOST` aponta para algo que não é um socket Unix (ex.: `tcp://`).
+    UnsupportedHost(String),
+    /// O daemon não está rodando ou o socket não pode ser aberto.
+    Unavailable { socket: PathBuf, source: io::Error },
+    /// A API respondeu com erro.
+    Api { status: u16, message: String },
+    /// Resposta que não é HTTP ou JSON válido.
+    InvalidResponse(String),
+}
+
+impl fmt::Display for DockerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DockerError::UnsupportedHost(host) => write!(f, "DOCKER_HOST não suportado: {}", host),
+            DockerError::Unavailable { socket, source } => {
+                write!(f, "Docker indisponível em {}: {}", socket.display(), source)
+            }
+            DockerError::Api { status, message } => write!(f, "Docker respondeu {}: {}", status, message),
+            DockerError::InvalidResponse(reason) => write!(f, "Resposta inválida do Docker: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for DockerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DockerError::Unavailable { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Imagem listada por `GET /images/json`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DockerImage {
+    pub id: String,
+    pub size: u64,
+}
+
+/// Volume listado por `GET /volumes`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DockerVolume {
+    pub name: String,
+}
+
+/// Registro do cache de build do BuildKit, de `GET /system/df`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BuildCacheRecord {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub size: u64,
+    #[serde(default)]
+    pub in_use: bool,
+    #[serde(default)]
+    created_at: Option<String>,
+    #[serde(default)]
+    last_used_at: Option<String>,
+}
+
+impl BuildCacheRecord {
+    pub fn created_at(&self) -> Option<SystemTime> {
+        self.created_at.as_deref().and_then(parse_timestamp)
+    }
+
+    /// Último uso num build; registros nunca reutilizados não têm.
+    pub fn last_used_at(&self) -> Option<SystemTime> {
+        self.last_used_at.as_deref().and_then(parse_timestamp)
+    }
+}
+
+/// Contêiner listado por `GET /containers/json`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DockerContainer {
+    pub id: String,
+    #[serde(default)]
+    pub names: Vec<String>,
+    /// Tamanho da camada gravável, só presente com `size=true`.
+    #[serde(default)]
+    pub size_rw: Option<u64>,
+    /// Segundos desde a época Unix.
+    #[serde(default)]
+    created: u64,
+}
+
+impl DockerContainer {
+    /// Nome sem a `/` inicial que a API acrescenta.
+    pub fn name(&self) -> Option<&str> {
+        self.names.first().map(|name| name.trim_start_matches('/'))
+    }
+
+    pub fn created_at(&self) -> Option<SystemTime> {
+        (self.created > 0).then(|| UNIX_EPOCH + Duration::from_secs(self.created))
+    }
+}
+
+/// O que um prune do cache de build removeu.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PruneReport {
+    /// IDs dos registros removidos.
+    pub deleted: Vec<String>,
+    pub space_reclaimed: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VolumeList {
+    #[serde(default)]
+    volumes: Option<Vec<DockerVolume>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct DiskUsage {
+    #[serde(default)]
+    build_cache: Option<Vec<BuildCacheRecord>>,
+    #[serde(default)]
+    volumes: Option<Vec<VolumeUsage>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VolumeUsage {
+    name: String,
+    #[serde(default)]
+    usage_data: Option<VolumeUsageData>,
+}
+
+/// Uso de um volume; o Docker informa `-1` quando não consegue medir.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VolumeUsageData {
+    size: i64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct BuildCachePrune {
+    #[serde(default)]
+    caches_deleted: Option<Vec<String>>,
+    #[serde(default)]
+    space_reclaimed: u64,
+}
+
+#[derive(Deserialize)]
+struct ApiMessage {
+    message: String,
+}
+
+/// Cliente da API do Docker Engine via HTTP sobre o socket Unix.
+/// Cada chamada abre uma conexão, faz uma requisição e a fecha.
+#[derive(Debug, Clone)]
+pub struct DockerClient {
+    host: String,
+}
+
+impl DockerClient {
+    /// Usa o `DOCKER_HOST` do ambiente ou o socket padrão.
+    pub fn from_env() -> Self {
+        Self {
+            host: std::env::var("DOCKER_HOST").unwrap_or_else(|_| DEFAULT_DOCKER_HOST.to_string()),
+        }
+    }
+
+    /// Fala com o daemon no socket informado.
+    #[allow(dead_code)]
+    pub fn with_socket(socket: &Path) -> Self {
+        Self { host: format!("unix://{}", socket.display()) }
+    }
+
+    /// Imagens sem tag e sem filhos, com o tamanho de cada uma.
+    pub fn dangling_images(&self) -> Result<Vec<DockerImage>, DockerError> {
+        self.get_json(&format!("/images/json?filters={}", encode_query(DANGLING_FILTER)))
+    }
+
+    /// Volumes que nenhum contêiner referencia.
+    pub fn dangling_volumes(&self) -> Result<Vec<DockerVolume>, DockerError> {
+        let list: VolumeList = self.get_json(&format!("/volumes?filters={}", encode_query(DANGLING_FILTER)))?;
+        Ok(list.volumes.unwrap_or_default())
+    }
+
+    /// Remove uma imagem pelo ID (`DELETE /images/{id}`), sem forçar:
+    /// uma imagem que passou a ser usada por um contêiner falha com 409.
+    pub fn remove_image(&self, id: &str) -> Result<(), DockerError> {
+        self.request("DELETE", &format!("/images/{}", encode_query(id))).map(|_| ())
+    }
+
+    /// Remove um volume pelo nome (`DELETE /volumes/{name}`).
+    /// Um volume que voltou a ser usado falha com 409.
+    pub fn remove_volume(&self, name: &str) -> Result<(), DockerError> {
+        self.request("DELETE", &format!("/volumes/{}", encode_query(name))).map(|_| ())
+    }
+
+    /// Espaço usado por volume, de `GET /system/df`.
+    /// Volumes que o Docker não conseguiu medir ficam com `None`.
+    pub fn volume_sizes(&self) -> Result<HashMap<String, Option<u64>>, DockerError> {
+        let usage: DiskUsage = self.get_json("/system/df?type=volume")?;
+        Ok(usage
+            .volumes
+            .unwrap_or_default()
+            .into_iter()
+            .map(|volume| {
+                let size = volume.usage_data.and_then(|usage| u64::try_from(usage.size).ok());
+                (volume.name, size)
+            })
+            .collect())
+    }
+
+    /// Registros do cache de build que nenhum build em andamento usa.
+    pub fn build_cache(&self) -> Result<Vec<BuildCacheRecord>, DockerError> {
+        let usage: DiskUsage = self.get_json("/system/df?type=build-cache")?;
+        Ok(usage.build_cache.unwrap_or_default().into_iter().filter(|record| !record.in_use).collect())
+    }
+
+    /// Contêineres encerrados, com o tamanho da camada gravável.
+    pub fn stopped_containers(&self) -> Result<Vec<DockerContainer>, DockerError> {
+        self.get_json(&format!("/containers/json?all=true&size=true&filters={}", encode_query(STOPPED_FILTER)))
+    }
+
+    /// Remove um único registro do cache de build (`POST /build/prune` filtrado pelo ID).
+    pub fn prune_build_cache(&self, id: &str) -> Result<PruneReport, DockerError> {
+        let filter = serde_json::json!({ "id": [id] }).to_string();
+        let body = self.request("POST", &format!("/build/prune?all=true&filters={}", encode_query(&filter)))?;
+        let prune: BuildCachePrune = parse_json(&body)?;
+        Ok(PruneReport {
+            deleted: prune.caches_deleted.unwrap_or_default(),
+            space_reclaimed: prune.space_reclaimed,
+        })
+    }
+
+    /// Remove um contêiner parado (`DELETE /containers/{id}`). Volumes ficam.
+    pub fn remove_container(&self, id: &str) -> Result<(), DockerError> {
+        self.request("DELETE", &format!("/containers/{}", encode_query(id))).map(|_| ())
+    }
+
+    fn get_json<T: DeserializeOwned>(&self, target: &str) -> Result<T, DockerError> {
+        parse_json(&self.request("GET", target)?)
+    }
+
+    /// Faz uma requisição HTTP/1.1 e devolve o corpo de uma resposta 2xx.
+    fn request(&self, method: &str, target: &str) -> Result<Vec<u8>, DockerError> {
+        let socket = socket_path(&self.host)?;
+        let unavailable = |source| DockerError::Unavailable { socket: socket.clone(), source };
+
+        let mut stream = UnixStream::connect(&socket).map_err(unavailable)?;
+        stream.set_read_timeout(Some(REQUEST_TIMEOUT)).map_err(unavailable)?;
+        write!(
+            stream,
+            "{} {} HTTP/1.1\r\nHost: docker\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            method, target
+        )
+        .map_err(unavailable)?;
+
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response).map_err(unavailable)?;
+
+        let (status, body) = parse_response(&response)?;
+        if !(200..300).contains(&status) {
+            let message = serde_json::from_slice::<ApiMessage>(&body)
+                .map(|api| api.message)
+                .unwrap_or_else(|_| String::from_utf8_lossy(&body).trim().to_string());
+            return Err(DockerError::Api { status, message });
+        }
+        Ok(body)
+    }
+}
+
+/// Caminho do socket de um `DOCKER_HOST` no formato `unix:///caminho`.
+fn socket_path(host: &str) -> Result<PathBuf, DockerError> {
+    host.strip_prefix("unix://")
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .ok_or_else(|| DockerError::UnsupportedHost(host.to_string()))
+}
+
+/// Separa status e corpo de uma resposta HTTP/1.1, com ou sem
+/// `Transfer-Encoding: chunked`.
+fn parse_response(response: &[u8]) -> Result<(u16, Vec<u8>), DockerError> {
+    let invalid = |reason: &str| DockerError::InvalidResponse(reason.to_string());
+
+    let split = response.windows(4).position(|window| window == b"\r\n\r\n").ok_or_else(|| invalid("cabeçalho incompleto"))?;
+    let head = String::from_utf8_lossy(&response[..split]);
+    let body = &response[split + 4..];
+
+    let mut lines = head.lines();
+    let status = lines
+        .next()
+        .and_then(|status_line| status_line.split_whitespace().nth(1))
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(|| invalid("linha de status inválida"))?;
+
+    let chunked = lines.any(|header| {
+        header.split_once(':').is_some_and(|(name, value)| {
+            name.trim().eq_ignore_ascii_case("transfer-encoding") && value.trim().eq_ignore_ascii_case("chunked")
+        })
+    });
+
+    let body = if chunked { decode_chunked(body).ok_or_else(|| invalid("corpo chunked inválido"))? } else { body.to_vec() };
+    Ok((status, body))
+}
+
+/// Junta os pedaços de um corpo `chunked` (`<tamanho hex>\r\n<dados>\r\n`, até o pedaço vazio).
+fn decode_chunked(mut body: &[u8]) -> Option<Vec<u8>> {
+    let mut decoded = Vec::new();
+    loop {
+        let line_end = body.windows(2).position(|window| window == b"\r\n")?;
+        let size_field = std::str::from_utf8(&body[..line_end]).ok()?;
+        let size = usize::from_str_radix(size_field.split(';').next()?.trim(), 16).ok()?;
+        body = &body[line_end + 2..];
+        if size == 0 {
+            return Some(decoded);
+        }
+
+        decoded.extend_from_slice(body.get(..size)?);
+        body = body.get(size + 2..)?;
+    }
+}
+
+fn parse_json<T: DeserializeOwned>(body: &[u8]) -> Result<T, DockerError> {
+    serde_json::from_slice(body).map_err(|e| DockerError::InvalidResponse(e.to_string()))
+}
+
+/// Codifica um valor para a query string (percent-encoding).
+fn encode_query(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Converte os horários RFC 3339 da API. A API usa a data zero do Go
+/// (`0001-01-01T00:00:00Z`) para "nunca", que vira `None`.
+fn parse_timestamp(value: &str) -> Option<SystemTime> {
+    let parsed = chrono::DateTime::parse_from_rfc3339(value).ok()?;
+    let seconds = u64::try_from(parsed.timestamp()).ok().filter(|seconds| *seconds > 0)?;
+    Some(UNIX_EPOCH + Duration::new(seconds, parsed.timestamp_subsec_nanos()))
+}
+
+/// ID curto de uma imagem, como o `docker images` mostra.
+pub fn short_id(id: &str) -> &str {
+    let id = id.strip_prefix("sha256:").unwrap_or(id);
+    &id[..12.min(id.len())]
+}
+
+/// Daemon falso: responde a cada `MÉTODO /caminho` com o status e o corpo
+/// dados, em `chunked` como o Docker, e guarda as requisições recebidas.
+#[cfg(test)]
+pub(crate) struct FakeDockerDaemon {
+    pub(crate) client: DockerClient,
+    requests: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+#[cfg(test)]
+impl FakeDockerDaemon {
+    pub(crate) fn start(dir: &Path, routes: &[(&str, u16, &str)]) -> Self {
+        use std::io::{BufRead, BufReader};
+        use std::os::unix::net::UnixListener;
+        use std::sync::{Arc, Mutex};
+
+        let socket = dir.join("docker.sock");
+        let listener = UnixListener::bind(&socket).unwrap();
+        let routes: Vec<(String, u16, String)> =
+            routes.iter().map(|(route, status, body)| (route.to_string(), *status, body.to_string())).collect();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let received = Arc::clone(&requests);
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().map_while(Result::ok) {
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut header = String::new();
+                while reader.read_line(&mut header).unwrap() > 2 {
+                    header.clear();
+                }
+
+                let mut fields = request_line.split_whitespace();
+                let (method, target) = (fields.next().unwrap_or(""), fields.next().unwrap_or(""));
+                let route = format!("{} {}", method, target.split('?').next().unwrap_or(""));
+                received.lock().unwrap().push(format!("{} {}", method, target));
+
+                let (status, body) = routes
+                    .iter()
+                    .find(|(known, _, _)| *known == route)
+                    .map(|(_, status, body)| (*status, body.as_str()))
+                    .unwrap_or((404, r#"{"message":"page not found"}"#));
+                let (first, rest) = body.split_at(body.len() / 2);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Type: application/json\r\nTransfer-Encoding: chunked\r\n\r\n{:x}\r\n{}\r\n{:x}\r\n{}\r\n0\r\n\r\n",
+                    status,
+                    first.len(),
+                    first,
+                    rest.len(),
+                    rest
+                );
+            }
+        });
+
+        Self { client: DockerClient::with_socket(&socket), requests }
+    }
+
+    /// Requisições recebidas, como `GET /images/json?filters=...`.
+    pub(crate) fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_list_dangling_images_in_a_single_request() {
+        let dir = tempfile::tempdir().unwrap();
+        let daemon = FakeDockerDaemon::start(
+            dir.path(),
+            &[("GET /images/json", 200, r#"[{"Id":"sha256:0123456789abcdef","Size":73400320,"RepoTags":["<none>:<none>"]}]"#)],
+        );
+
+        let images = daemon.client.dangling_images().unwrap();
+
+        assert_eq!(images, [DockerImage { id: "sha256:0123456789abcdef".to_string(), size: 73_400_320 }]);
+        assert_eq!(
+            daemon.requests(),
+            ["GET /images/json?filters=%7B%22dangling%22%3A%5B%22true%22%5D%7D"]
+        );
+        assert_eq!(short_id(&images[0].id), "0123456789ab");
+    }
+
+    #[test]
+    fn should_report_api_errors_and_unreachable_daemon() {
+        let dir = tempfile::tempdir().unwrap();
+        let daemon = FakeDockerDaemon::start(
+            dir.path(),
+            &[("DELETE /volumes/dados", 409, r#"{"message":"remove dados: volume is in use"}"#)],
+        );
+
+        match daemon.client.remove_volume("dados") {
+            Err(DockerError::Api { status, message }) => {
+                assert_eq!(status, 409);
+                assert_eq!(message, "remove dados: volume is in use");
+            }
+            other => panic!("esperava erro da API, veio {:?}", other),
+        }
+
+        let down = DockerClient::with_socket(&dir.path().join("parado.sock"));
+        assert!(matches!(down.dangling_images(), Err(DockerError::Unavailable { .. })));
+
+        let tcp = DockerClient { host: "tcp://127.0.0.1:2375".to_string() };
+        assert!(matches!(tcp.dangling_volumes(), Err(DockerError::UnsupportedHost(_))));
+    }
+
+    #[test]
+    fn should_list_build_cache_and_stopped_containers() {
+        let dir = tempfile::tempdir().unwrap();
+        let daemon = FakeDockerDaemon::start(
+            dir.path(),
+            &[
+                (
+                    "GET /system/df",
+                    200,
+                    r#"{"BuildCache":[
+                        {"ID":"k3x9","Size":5000,"InUse":false,"CreatedAt":"2024-01-01T10:00:00.5Z","LastUsedAt":"2024-02-01T10:00:00Z"},
+                        {"ID":"busy","Size":7000,"InUse":true,"CreatedAt":"2024-01-01T10:00:00Z","LastUsedAt":null}
+                    ]}"#,
+                ),
+                ("GET /containers/json", 200, r#"[{"Id":"3f2a1b4c5d6e7f80","Names":["/web-old"],"SizeRw":1234,"Created":1700000000,"State":"exited"}]"#),
+                ("POST /build/prune", 200, r#"{"CachesDeleted":["k3x9"],"SpaceReclaimed":5000}"#),
+                ("DELETE /containers/3f2a1b4c5d6e", 204, ""),
+            ],
+        );
+
+        let cache = daemon.client.build_cache().unwrap();
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache[0].last_used_at(), Some(UNIX_EPOCH + Duration::from_secs(1_706_781_600)));
+        assert_eq!(cache[0].created_at(), Some(UNIX_EPOCH + Duration::new(1_704_103_200, 500_000_000)));
+
+        let containers = daemon.client.stopped_containers().unwrap();
+        assert_eq!(containers[0].name(), Some("web-old"));
+        assert_eq!(containers[0].size_rw, Some(1234));
+        assert_eq!(containers[0].created_at(), Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000)));
+
+        assert_eq!(daemon.client.prune_build_cache("k3x9").unwrap().deleted, ["k3x9"]);
+        daemon.client.remove_container("3f2a1b4c5d6e").unwrap();
+        assert_eq!(
+            daemon.requests()[2..],
+            [
+                "POST /build/prune?all=true&filters=%7B%22id%22%3A%5B%22k3x9%22%5D%7D",
+                "DELETE /containers/3f2a1b4c5d6e",
+            ]
+        );
+        assert_eq!(parse_timestamp("0001-01-01T00:00:00Z"), None);
+    }
+
+    #[test]
+    fn should_read_volume_sizes_from_disk_usage() {
+        let dir = tempfile::tempdir().unwrap();
+        let daemon = FakeDockerDaemon::start(
+            dir.path(),
+            &[(
+                "GET /system/df",
+                200,
+                r#"{"Volumes":[
+                    {"Name":"dados","UsageData":{"Size":52428800,"RefCount":0}},
+                    {"Name":"remoto","UsageData":{"Size":-1,"RefCount":-1}},
+                    {"Name":"antigo","UsageData":null}
+                ]}"#,
+            )],
+        );
+
+        let sizes = daemon.client.volume_sizes().unwrap();
+
+        assert_eq!(sizes["dados"], Some(52_428_800));
+        assert_eq!(sizes["remoto"], None);
+        assert_eq!(sizes["antigo"], None);
+        assert_eq!(daemon.requests(), ["GET /system/df?type=volume"]);
+    }
+
+    #[test]
+    fn should_parse_plain_and_chunked_responses() {
+        let plain = b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n[]";
+        assert_eq!(parse_response(plain).unwrap(), (200, b"[]".to_vec()));
+
+        let chunked = b"HTTP/1.1 200 OK\r\ntransfer-encoding: chunked\r\n\r\n3\r\n{\"a\r\n4\r\n\":1}\r\n0\r\n\r\n";
+        assert_eq!(parse_response(chunked).unwrap(), (200, br#"{"a":1}"#.to_vec()));
+
+        assert!(matches!(parse_response(b"lixo"), Err(DockerError::InvalidResponse(_))));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod docker_client;
+
+pub use docker_client::{DockerClient, short_id};
@@ -1,3 +1,8 @@
+pub mod command;
+pub mod config;
+pub mod docker;
+pub mod platform;
 pub mod repositories;
 
+pub use config::AppConfig;
 pub use repositories::{FileSystemScannerRepository, FileSystemCleanerRepository};
@@ -0,0 +1,3 @@
+pub mod platform_paths;
+
+pub use platform_paths::{BrowserDirs, BrowserFamily, PlatformPaths, default_platform_paths};
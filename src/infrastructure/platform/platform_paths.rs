@@ -0,0 +1,414 @@
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Provedor dos locais de varredura específicos de cada plataforma.
+/// Permite trocar os caminhos do sistema nos testes.
+pub trait PlatformPaths: Send + Sync {
+    /// Diretório home do usuário.
+    fn home(&self) -> &Path;
+
+    /// Diretórios onde ficam arquivos de log.
+    fn log_dirs(&self) -> Vec<PathBuf>;
+
+    /// Diretórios de arquivos temporários e caches de usuário.
+    fn temp_dirs(&self) -> Vec<PathBuf>;
+
+    /// Caches de gerenciadores de pacotes (npm, etc).
+    fn package_cache_dirs(&self) -> Vec<PathBuf>;
+
+    /// Diretório do cargo (`$CARGO_HOME` ou `~/.cargo`).
+    fn cargo_home(&self) -> PathBuf;
+
+    /// Lixeiras no formato freedesktop (com `files/` e `info/`).
+    fn trash_dirs(&self) -> Vec<PathBuf>;
+
+    /// Diretórios de dados e de cache dos navegadores suportados.
+    fn browser_dirs(&self) -> Vec<BrowserDirs>;
+
+    /// Diretórios de core dumps e relatórios de falha.
+    fn crash_dump_dirs(&self) -> Vec<PathBuf>;
+
+    /// Diretório dos pacotes `.snap` instalados, onde existe snapd.
+    fn snap_dir(&self) -> Option<PathBuf>;
+}
+
+/// Família do navegador, que define como os perfis são encontrados.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowserFamily {
+    /// Perfis listados em `profiles.ini`.
+    Firefox,
+    /// Perfis em `Default` e `Profile N`.
+    Chromium,
+}
+
+/// Locais de um navegador: `data_dir` guarda os perfis e `cache_dir`
+/// espelha os mesmos perfis só com dados descartáveis.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrowserDirs {
+    pub browser: &'static str,
+    pub family: BrowserFamily,
+    pub data_dir: PathBuf,
+    pub cache_dir: PathBuf,
+}
+
+impl BrowserDirs {
+    fn new(browser: &'static str, family: BrowserFamily, data_dir: PathBuf, cache_dir: PathBuf) -> Self {
+        Self { browser, family, data_dir, cache_dir }
+    }
+}
+
+/// Retorna o provedor adequado à plataforma atual.
+pub fn default_platform_paths() -> Arc<dyn PlatformPaths> {
+    let home = std::env::var_os("HOME").map(PathBuf::from).unwrap_or_default();
+
+    if cfg!(target_os = "macos") {
+        Arc::new(MacOsPlatformPaths::new(home))
+    } else {
+        Arc::new(LinuxPlatformPaths::from_env(home))
+    }
+}
+
+/// Locais de varredura do Linux, seguindo a especificação XDG Base Directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinuxPlatformPaths {
+    home: PathBuf,
+    cache_home: PathBuf,
+    config_home: PathBuf,
+    state_home: PathBuf,
+    data_home: PathBuf,
+    cargo_home: PathBuf,
+}
+
+impl LinuxPlatformPaths {
+    /// Resolve os diretórios XDG a partir das variáveis de ambiente do processo.
+    pub fn from_env(home: PathBuf) -> Self {
+        Self::resolve(home, |name| std::env::var_os(name).map(PathBuf::from))
+    }
+
+    /// Resolve os diretórios XDG usando `lookup` para ler as variáveis.
+    /// Valores vazios ou relativos são ignorados, como manda a especificação.
+    pub fn resolve<F>(home: PathBuf, lookup: F) -> Self
+    where
+        F: Fn(&str) -> Option<PathBuf>,
+    {
+        let xdg_dir = |name: &str, fallback: &str| {
+            lookup(name)
+                .filter(|dir| dir.is_absolute())
+                .unwrap_or_else(|| home.join(fallback))
+        };
+
+        Self {
+            cache_home: xdg_dir("XDG_CACHE_HOME", ".cache"),
+            config_home: xdg_dir("XDG_CONFIG_HOME", ".config"),
+            state_home: xdg_dir("XDG_STATE_HOME", ".local/state"),
+            data_home: xdg_dir("XDG_DATA_HOME", ".local/share"),
+            cargo_home: xdg_dir("CARGO_HOME", ".cargo"),
+            home,
+        }
+    }
+}
+
+impl PlatformPaths for LinuxPlatformPaths {
+    fn home(&self) -> &Path {
+        &self.home
+    }
+
+    fn log_dirs(&self) -> Vec<PathBuf> {
+        vec![
+            PathBuf::from("/var/log"),
+            PathBuf::from("/tmp"),
+            self.state_home.clone(),
+        ]
+    }
+
+    fn temp_dirs(&self) -> Vec<PathBuf> {
+        vec![
+            PathBuf::from("/tmp"),
+            PathBuf::from("/var/tmp"),
+            self.cache_home.clone(),
+        ]
+    }
+
+    fn package_cache_dirs(&self) -> Vec<PathBuf> {
+        // O registry do cargo fica na categoria de artefatos Rust, separado por parte
+        vec![self.home.join(".npm")]
+    }
+
+    fn cargo_home(&self) -> PathBuf {
+        self.cargo_home.clone()
+    }
+
+    fn trash_dirs(&self) -> Vec<PathBuf> {
+        // SAFETY: getuid não tem pré-condições e nunca falha
+        let uid = unsafe { libc::getuid() };
+        let mut dirs = vec![self.data_home.join("Trash")];
+
+        let mounts = fs::read_to_string("/proc/self/mounts").unwrap_or_default();
+        for top_dir in parse_mount_points(&mounts) {
+            for dir in mount_trash_dirs(&top_dir, uid) {
+                if !dirs.contains(&dir) {
+                    dirs.push(dir);
+                }
+            }
+        }
+
+        dirs
+    }
+
+    fn browser_dirs(&self) -> Vec<BrowserDirs> {
+        let config_home = &self.config_home;
+        let cache_home = &self.cache_home;
+
+        vec![
+            BrowserDirs::new(
+                "Firefox",
+                BrowserFamily::Firefox,
+                self.home.join(".mozilla/firefox"),
+                cache_home.join("mozilla/firefox"),
+            ),
+            BrowserDirs::new(
+                "Chromium",
+                BrowserFamily::Chromium,
+                config_home.join("chromium"),
+                cache_home.join("chromium"),
+            ),
+            BrowserDirs::new(
+                "Chrome",
+                BrowserFamily::Chromium,
+                config_home.join("google-chrome"),
+                cache_home.join("google-chrome"),
+            ),
+            BrowserDirs::new(
+                "Brave",
+                BrowserFamily::Chromium,
+                config_home.join("BraveSoftware/Brave-Browser"),
+                cache_home.join("BraveSoftware/Brave-Browser"),
+            ),
+        ]
+    }
+
+    fn crash_dump_dirs(&self) -> Vec<PathBuf> {
+        vec![
+            PathBuf::from("/var/lib/systemd/coredump"),
+            PathBuf::from("/var/crash"),
+            self.data_home.join("apport"),
+        ]
+    }
+
+    fn snap_dir(&self) -> Option<PathBuf> {
+        Some(PathBuf::from("/var/lib/snapd/snaps"))
+    }
+}
+
+/// Lixeiras de um ponto de montagem: `$topdir/.Trash/$uid`, aceita só se
+/// `.Trash` for um diretório real com sticky bit, e `$topdir/.Trash-$uid`.
+fn mount_trash_dirs(top_dir: &Path, uid: u32) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+
+    let shared = top_dir.join(".Trash");
+    let shared_is_valid = fs::symlink_metadata(&shared)
+        .is_ok_and(|metadata| metadata.is_dir() && metadata.permissions().mode() & 0o1000 != 0);
+    if shared_is_valid && shared.join(uid.to_string()).is_dir() {
+        dirs.push(shared.join(uid.to_string()));
+    }
+
+    let own = top_dir.join(format!(".Trash-{}", uid));
+    if fs::symlink_metadata(&own).is_ok_and(|metadata| metadata.is_dir()) {
+        dirs.push(own);
+    }
+
+    dirs
+}
+
+/// Extrai os pontos de montagem de `/proc/self/mounts`.
+/// Espaços e outros caracteres vêm escapados em octal (`\040`).
+fn parse_mount_points(mounts: &str) -> Vec<PathBuf> {
+    mounts
+        .lines()
+        .filter_map(|line| line.split_whitespace().nth(1))
+        .map(|mount_point| PathBuf::from(unescape_octal(mount_point)))
+        .collect()
+}
+
+fn unescape_octal(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let escaped = bytes.get(index + 1..index + 4)
+            .filter(|_| bytes[index] == b'\\')
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .and_then(|digits| u8::from_str_radix(digits, 8).ok());
+
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 4;
+            }
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Locais de varredura do macOS.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MacOsPlatformPaths {
+    home: PathBuf,
+}
+
+impl MacOsPlatformPaths {
+    pub fn new(home: PathBuf) -> Self {
+        Self { home }
+    }
+}
+
+impl PlatformPaths for MacOsPlatformPaths {
+    fn home(&self) -> &Path {
+        &self.home
+    }
+
+    // `/tmp` e `/var` são links para `/private`, e a varredura não segue raízes que são links
+    fn log_dirs(&self) -> Vec<PathBuf> {
+        vec![
+            PathBuf::from("/private/var/log"),
+            PathBuf::from("/private/tmp"),
+            self.home.join("Library/Logs"),
+        ]
+    }
+
+    fn temp_dirs(&self) -> Vec<PathBuf> {
+        vec![
+            PathBuf::from("/private/tmp"),
+            PathBuf::from("/private/var/tmp"),
+            self.home.join("Library/Caches"),
+        ]
+    }
+
+    fn package_cache_dirs(&self) -> Vec<PathBuf> {
+        // O registry do cargo fica na categoria de artefatos Rust, separado por parte
+        vec![self.home.join(".npm")]
+    }
+
+    fn cargo_home(&self) -> PathBuf {
+        std::env::var_os("CARGO_HOME")
+            .map(PathBuf::from)
+            .filter(|dir| dir.is_absolute())
+            .unwrap_or_else(|| self.home.join(".cargo"))
+    }
+
+    fn trash_dirs(&self) -> Vec<PathBuf> {
+        // ~/.Trash do Finder não guarda `.trashinfo`
+        Vec::new()
+    }
+
+    fn browser_dirs(&self) -> Vec<BrowserDirs> {
+        let support = self.home.join("Library/Application Support");
+        let caches = self.home.join("Library/Caches");
+
+        vec![
+            BrowserDirs::new("Firefox", BrowserFamily::Firefox, support.join("Firefox"), caches.join("Firefox")),
+            BrowserDirs::new("Chromium", BrowserFamily::Chromium, support.join("Chromium"), caches.join("Chromium")),
+            BrowserDirs::new(
+                "Chrome",
+                BrowserFamily::Chromium,
+                support.join("Google/Chrome"),
+                caches.join("Google/Chrome"),
+            ),
+            BrowserDirs::new(
+                "Brave",
+                BrowserFamily::Chromium,
+                support.join("BraveSoftware/Brave-Browser"),
+                caches.join("BraveSoftware/Brave-Browser"),
+            ),
+        ]
+    }
+
+    fn crash_dump_dirs(&self) -> Vec<PathBuf> {
+        vec![self.home.join("Library/Logs/DiagnosticReports")]
+    }
+
+    fn snap_dir(&self) -> Option<PathBuf> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_fall_back_to_home_based_xdg_dirs() {
+        let paths = LinuxPlatformPaths::resolve(PathBuf::from("/home/dev"), |_| None);
+
+        assert!(paths.temp_dirs().contains(&PathBuf::from("/home/dev/.cache")));
+        assert_eq!(paths.cargo_home(), PathBuf::from("/home/dev/.cargo"));
+        assert!(paths.log_dirs().contains(&PathBuf::from("/home/dev/.local/state")));
+        // `~/.local/share` contém a lixeira, varrida em outra categoria
+        assert!(!paths.log_dirs().contains(&PathBuf::from("/home/dev/.local/share")));
+        assert!(paths.crash_dump_dirs().contains(&PathBuf::from("/home/dev/.local/share/apport")));
+    }
+
+    #[test]
+    fn should_honour_xdg_environment_variables() {
+        let paths = LinuxPlatformPaths::resolve(PathBuf::from("/home/dev"), |name| match name {
+            "XDG_CACHE_HOME" => Some(PathBuf::from("/scratch/cache")),
+            "XDG_STATE_HOME" => Some(PathBuf::from("relative/state")),
+            _ => None,
+        });
+
+        assert!(paths.temp_dirs().contains(&PathBuf::from("/scratch/cache")));
+        assert!(paths.log_dirs().contains(&PathBuf::from("/home/dev/.local/state")));
+    }
+
+    #[test]
+    fn should_place_chromium_profiles_in_config_and_caches_in_cache_home() {
+        let paths = LinuxPlatformPaths::resolve(PathBuf::from("/home/dev"), |_| None);
+        let chrome = paths.browser_dirs().into_iter().find(|dirs| dirs.browser == "Chrome").unwrap();
+
+        assert_eq!(chrome.family, BrowserFamily::Chromium);
+        assert_eq!(chrome.data_dir, PathBuf::from("/home/dev/.config/google-chrome"));
+        assert_eq!(chrome.cache_dir, PathBuf::from("/home/dev/.cache/google-chrome"));
+    }
+
+    #[test]
+    fn should_parse_escaped_mount_points() {
+        let mounts = "proc /proc proc rw 0 0\n/dev/sdb1 /media/dev/My\\040Disk ext4 rw 0 0\n";
+
+        assert_eq!(
+            parse_mount_points(mounts),
+            vec![PathBuf::from("/proc"), PathBuf::from("/media/dev/My Disk")]
+        );
+    }
+
+    #[test]
+    fn should_find_per_mount_trash_dirs() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join(".Trash-1000")).unwrap();
+        fs::create_dir_all(dir.path().join(".Trash/1000")).unwrap();
+
+        // Sem sticky bit o `.Trash` compartilhado é ignorado
+        assert_eq!(mount_trash_dirs(dir.path(), 1000), vec![dir.path().join(".Trash-1000")]);
+
+        fs::set_permissions(dir.path().join(".Trash"), fs::Permissions::from_mode(0o1777)).unwrap();
+        assert_eq!(
+            mount_trash_dirs(dir.path(), 1000),
+            vec![dir.path().join(".Trash/1000"), dir.path().join(".Trash-1000")]
+        );
+    }
+
+    #[test]
+    fn should_keep_macos_library_locations() {
+        let paths = MacOsPlatformPaths::new(PathBuf::from("/Users/dev"));
+
+        assert!(paths.log_dirs().contains(&PathBuf::from("/Users/dev/Library/Logs")));
+        assert!(paths.temp_dirs().contains(&PathBuf::from("/Users/dev/Library/Caches")));
+    }
+}
@@ -0,0 +1,201 @@
+use crate::domain::entities::CleanableItem;
+use crate::domain::value_objects::ItemKind;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Gerenciador de aplicativos Flatpak.
+pub(super) const FLATPAK: &str = "flatpak";
+
+/// Gerenciador de pacotes Snap.
+pub(super) const SNAP: &str = "snap";
+
+/// Runtimes instalados, em todas as instalações, e o tamanho de cada um,
+/// em colunas separadas por tabulação.
+pub(super) const FLATPAK_RUNTIMES_ARGS: &[&str] = &["list", "--runtime", "--columns=ref,size"];
+
+/// Runtime usado por cada aplicativo instalado.
+pub(super) const FLATPAK_APP_RUNTIMES_ARGS: &[&str] = &["list", "--app", "--columns=runtime"];
+
+/// Todas as revisões instaladas, inclusive as desativadas.
+pub(super) const SNAP_LIST_ARGS: &[&str] = &["list", "--all"];
+
+/// Extensões que acompanham um runtime e ficam sem uso junto com ele.
+const RUNTIME_COMPANIONS: &[&str] = &[".Locale", ".Debug"];
+
+/// Runtime base (`id/arch/branch`) de um Platform, Sdk ou de uma das suas
+/// extensões de tradução e depuração. Outras extensões (drivers GL, codecs,
+/// temas) são usadas por runtimes, o que só os metadados de cada um dizem,
+/// então nunca são consideradas sem uso.
+fn base_runtime(flatpak_ref: &str) -> Option<String> {
+    let (id, arch_and_branch) = flatpak_ref.split_once('/')?;
+    let id = RUNTIME_COMPANIONS.iter().find_map(|suffix| id.strip_suffix(suffix)).unwrap_or(id);
+    (id.ends_with(".Platform") || id.ends_with(".Sdk")).then(|| format!("{}/{}", id, arch_and_branch))
+}
+
+/// Runtimes que nenhum aplicativo instalado usa, a partir das saídas do
+/// `flatpak list --runtime --columns=ref,size` e do
+/// `flatpak list --app --columns=runtime`. Só consultas de leitura são
+/// usadas; o tamanho vem no formato do GLib (`1,2 GB`, `356.5 MB`).
+pub(super) fn unused_flatpak_runtimes(runtimes: &str, app_runtimes: &str) -> Vec<(String, Option<u64>)> {
+    let in_use: Vec<&str> = app_runtimes.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+    let mut unused: Vec<(String, Option<u64>)> = Vec::new();
+
+    for line in runtimes.lines() {
+        let mut fields = line.split('\t');
+        let Some(flatpak_ref) = fields.next().map(str::trim).filter(|field| !field.is_empty()) else {
+            continue;
+        };
+        let Some(base) = base_runtime(flatpak_ref) else {
+            continue;
+        };
+
+        // O mesmo ref pode estar nas instalações do sistema e do usuário
+        if !in_use.contains(&base.as_str()) && !unused.iter().any(|(listed, _)| listed == flatpak_ref) {
+            unused.push((flatpak_ref.to_string(), fields.next().and_then(parse_si_size)));
+        }
+    }
+
+    unused
+}
+
+/// Converte tamanhos do GLib (`g_format_size`), em potências de 1000.
+fn parse_si_size(value: &str) -> Option<u64> {
+    let value = value.replace('\u{a0}', " ");
+    let (number, unit) = value.trim().split_once(' ')?;
+    let multiplier = match unit.trim() {
+        "byte" | "bytes" | "B" => 1.0,
+        "kB" => 1e3,
+        "MB" => 1e6,
+        "GB" => 1e9,
+        "TB" => 1e12,
+        _ => return None,
+    };
+
+    let number: f64 = number.replace(',', ".").parse().ok()?;
+    Some((number * multiplier) as u64)
+}
+
+/// Revisão instalada de um snap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct SnapRevision {
+    pub(super) name: String,
+    /// Número da revisão; instalações locais usam o prefixo `x` (`x1`).
+    pub(super) revision: String,
+}
+
+impl SnapRevision {
+    /// Nome do pacote em disco, `<nome>_<revisão>.snap`.
+    pub(super) fn file_name(&self) -> String {
+        format!("{}_{}.snap", self.name, self.revision)
+    }
+
+    /// Recupera nome e revisão do arquivo do pacote.
+    /// O nome pode ter `_` (instâncias paralelas), a revisão não.
+    pub(super) fn from_file(path: &Path) -> Option<Self> {
+        let stem = path.file_name()?.to_str()?.strip_suffix(".snap")?;
+        let (name, revision) = stem.rsplit_once('_')?;
+        Some(Self { name: name.to_string(), revision: revision.to_string() })
+    }
+
+    fn number(&self) -> u64 {
+        self.revision.trim_start_matches('x').parse().unwrap_or(0)
+    }
+}
+
+/// Revisões desativadas do `snap list --all` que podem ser removidas:
+///
+/// ```text
+/// Name    Version   Rev   Tracking       Publisher   Notes
+/// core18  20230901  2790  latest/stable  canonical✓  base,disabled
+/// ```
+///
+/// A revisão ativa fica sempre. Um snap todo desativado (`snap disable`)
+/// mantém a revisão mais nova, para que possa ser reativado.
+pub(super) fn parse_removable_snap_revisions(output: &str) -> Vec<SnapRevision> {
+    let mut snaps: BTreeMap<&str, Vec<(SnapRevision, bool)>> = BTreeMap::new();
+
+    for line in output.lines().skip(1) {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let (Some(name), Some(revision), Some(notes)) = (fields.first(), fields.get(2), fields.last()) else {
+            continue;
+        };
+        let disabled = notes.split(',').any(|note| note == "disabled");
+        let revision = SnapRevision { name: name.to_string(), revision: revision.to_string() };
+        snaps.entry(name).or_default().push((revision, disabled));
+    }
+
+    let mut removable = Vec::new();
+    for (_, mut revisions) in snaps {
+        if revisions.iter().all(|(_, disabled)| *disabled) {
+            revisions.sort_by_key(|(revision, _)| revision.number());
+            revisions.pop();
+        }
+        removable.extend(revisions.into_iter().filter(|(_, disabled)| *disabled).map(|(revision, _)| revision));
+    }
+    removable
+}
+
+/// Programa e argumentos que removem um runtime listado pela varredura.
+pub(super) fn uninstall_command(item: &CleanableItem) -> Option<(&'static str, Vec<String>)> {
+    match item.kind() {
+        ItemKind::FlatpakRuntime => Some((
+            FLATPAK,
+            vec!["uninstall".to_string(), "--noninteractive".to_string(), item.path().to_string()],
+        )),
+        ItemKind::SnapRevision => {
+            let snap = SnapRevision::from_file(Path::new(item.path()))?;
+            Some((SNAP, vec!["remove".to_string(), snap.name, format!("--revision={}", snap.revision)]))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_list_runtimes_no_installed_app_uses() {
+        let runtimes = "\
+org.gnome.Platform/x86_64/43\t1,2\u{a0}GB
+org.gnome.Platform.Locale/x86_64/43\t16.4 kB
+org.gnome.Platform/x86_64/45\t1.1 GB
+org.gnome.Platform.Locale/x86_64/45\t20.1 kB
+org.gnome.Sdk/x86_64/43\t
+org.freedesktop.Platform.GL.default/x86_64/23.08\t356.5 MB
+org.gtk.Gtk3theme.Adwaita-dark/x86_64/3.22\t120.0 kB
+org.gnome.Platform/x86_64/43\t1,2 GB
+";
+        let apps = "org.gnome.Platform/x86_64/45\norg.gnome.Platform/x86_64/45\n";
+
+        assert_eq!(
+            unused_flatpak_runtimes(runtimes, apps),
+            [
+                ("org.gnome.Platform/x86_64/43".to_string(), Some(1_200_000_000)),
+                ("org.gnome.Platform.Locale/x86_64/43".to_string(), Some(16_400)),
+                ("org.gnome.Sdk/x86_64/43".to_string(), None),
+            ]
+        );
+        assert!(unused_flatpak_runtimes("", apps).is_empty());
+    }
+
+    #[test]
+    fn should_keep_active_and_newest_snap_revisions() {
+        let list = "\
+Name       Version    Rev    Tracking       Publisher   Notes
+core18     20230901   2790   latest/stable  canonical✓  base,disabled
+core18     20231027   2796   latest/stable  canonical✓  base
+firefox    118.0      3206   latest/stable  mozilla✓    disabled
+firefox    119.0      3252   latest/stable  mozilla✓    disabled
+hello_dev  1.0        x1     -              -           disabled
+hello_dev  1.1        x2     -              -           disabled
+";
+        let removable: Vec<String> = parse_removable_snap_revisions(list).iter().map(SnapRevision::file_name).collect();
+
+        assert_eq!(removable, ["core18_2790.snap", "firefox_3206.snap", "hello_dev_x1.snap"]);
+        assert_eq!(
+            SnapRevision::from_file(Path::new("/var/lib/snapd/snaps/hello_dev_x1.snap")),
+            Some(SnapRevision { name: "hello_dev".to_string(), revision: "x1".to_string() })
+        );
+    }
+}
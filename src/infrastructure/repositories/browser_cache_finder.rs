@@ -0,0 +1,221 @@
+use crate::infrastructure::platform::{BrowserDirs, BrowserFamily};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Diretórios descartáveis de um perfil do Firefox.
+const FIREFOX_CACHE_DIRS: &[&str] = &["cache2"];
+
+/// Diretórios descartáveis de um perfil do Chromium e derivados.
+const CHROMIUM_CACHE_DIRS: &[&str] = &["Cache", "Code Cache", "GPUCache"];
+
+/// Perfil de navegador com os diretórios de cache encontrados.
+/// Histórico, cookies e demais dados do perfil nunca entram na lista.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct BrowserProfile {
+    /// Navegador e nome do perfil, ex.: `Firefox · default-release`.
+    pub(super) label: String,
+    pub(super) cache_dirs: Vec<PathBuf>,
+}
+
+/// Lista os perfis de um navegador que têm algum diretório de cache.
+pub(super) fn browser_profiles(dirs: &BrowserDirs) -> Vec<BrowserProfile> {
+    let (profiles, cache_names) = match dirs.family {
+        BrowserFamily::Firefox => (firefox_profiles(dirs), FIREFOX_CACHE_DIRS),
+        BrowserFamily::Chromium => (chromium_profiles(dirs), CHROMIUM_CACHE_DIRS),
+    };
+
+    let mut found: Vec<BrowserProfile> = profiles
+        .into_iter()
+        .filter_map(|(name, roots)| {
+            let cache_dirs: Vec<PathBuf> = roots
+                .iter()
+                .flat_map(|root| cache_names.iter().map(move |cache| root.join(cache)))
+                .filter(|dir| is_real_dir(dir))
+                .collect();
+
+            if cache_dirs.is_empty() {
+                return None;
+            }
+
+            Some(BrowserProfile {
+                label: format!("{} · {}", dirs.browser, name),
+                cache_dirs,
+            })
+        })
+        .collect();
+
+    found.sort_by(|a, b| a.label.cmp(&b.label));
+    found
+}
+
+/// Perfis do `profiles.ini`, com o diretório do perfil e o espelho em `cache_dir`.
+fn firefox_profiles(dirs: &BrowserDirs) -> Vec<(String, Vec<PathBuf>)> {
+    let Ok(content) = fs::read_to_string(dirs.data_dir.join("profiles.ini")) else {
+        return Vec::new();
+    };
+
+    parse_profiles_ini(&content)
+        .into_iter()
+        .map(|profile| {
+            let roots = if profile.is_relative {
+                vec![dirs.data_dir.join(&profile.path), dirs.cache_dir.join(&profile.path)]
+            } else {
+                vec![PathBuf::from(&profile.path)]
+            };
+            (profile.name, roots)
+        })
+        .collect()
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct IniProfile {
+    name: String,
+    path: String,
+    is_relative: bool,
+}
+
+/// Lê as seções `[ProfileN]` do `profiles.ini`.
+fn parse_profiles_ini(content: &str) -> Vec<IniProfile> {
+    let mut profiles = Vec::new();
+    let mut current: Option<IniProfile> = None;
+
+    for line in content.lines().map(str::trim) {
+        if line.starts_with('[') {
+            profiles.extend(current.take());
+            if line.starts_with("[Profile") {
+                current = Some(IniProfile {
+                    is_relative: true,
+                    ..IniProfile::default()
+                });
+            }
+            continue;
+        }
+
+        let (Some(profile), Some((key, value))) = (current.as_mut(), line.split_once('=')) else {
+            continue;
+        };
+        match key {
+            "Name" => profile.name = value.to_string(),
+            "Path" => profile.path = value.to_string(),
+            "IsRelative" => profile.is_relative = value != "0",
+            _ => {}
+        }
+    }
+    profiles.extend(current);
+
+    profiles
+        .into_iter()
+        .filter(|profile| !profile.path.is_empty())
+        .map(|mut profile| {
+            if profile.name.is_empty() {
+                profile.name = profile.path.clone();
+            }
+            profile
+        })
+        .collect()
+}
+
+/// Perfis `Default` e `Profile N`, nomeados pelo `Local State` quando possível.
+fn chromium_profiles(dirs: &BrowserDirs) -> Vec<(String, Vec<PathBuf>)> {
+    let Ok(entries) = fs::read_dir(&dirs.data_dir) else {
+        return Vec::new();
+    };
+    let local_state = fs::read_to_string(dirs.data_dir.join("Local State"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok());
+
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_dir()))
+        .filter_map(|entry| {
+            let dir_name = entry.file_name().to_string_lossy().to_string();
+            if dir_name != "Default" && !dir_name.starts_with("Profile ") {
+                return None;
+            }
+
+            let name = local_state
+                .as_ref()
+                .and_then(|state| state["profile"]["info_cache"][&dir_name]["name"].as_str())
+                .map(str::to_string)
+                .unwrap_or_else(|| dir_name.clone());
+
+            Some((name, vec![entry.path(), dirs.cache_dir.join(&dir_name)]))
+        })
+        .collect()
+}
+
+/// Diretório de verdade, sem seguir links.
+fn is_real_dir(path: &Path) -> bool {
+    fs::symlink_metadata(path).is_ok_and(|metadata| metadata.is_dir())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dirs(root: &Path, browser: &'static str, family: BrowserFamily) -> BrowserDirs {
+        BrowserDirs {
+            browser,
+            family,
+            data_dir: root.join("data"),
+            cache_dir: root.join("cache"),
+        }
+    }
+
+    #[test]
+    fn should_find_firefox_caches_from_profiles_ini() {
+        let dir = tempfile::tempdir().unwrap();
+        let firefox = dirs(dir.path(), "Firefox", BrowserFamily::Firefox);
+        fs::create_dir_all(firefox.data_dir.join("abc.default-release/cache2")).unwrap();
+        fs::create_dir_all(firefox.cache_dir.join("abc.default-release/cache2")).unwrap();
+        fs::write(firefox.data_dir.join("abc.default-release/places.sqlite"), b"history").unwrap();
+        fs::create_dir_all(firefox.data_dir.join("xyz.vazio")).unwrap();
+        fs::write(
+            firefox.data_dir.join("profiles.ini"),
+            "[General]\nStartWithLastProfile=1\n\n[Profile0]\nName=default-release\nIsRelative=1\nPath=abc.default-release\n\n[Profile1]\nName=vazio\nIsRelative=1\nPath=xyz.vazio\n",
+        )
+        .unwrap();
+
+        let profiles = browser_profiles(&firefox);
+
+        assert_eq!(profiles.len(), 1);
+        assert_eq!(profiles[0].label, "Firefox · default-release");
+        assert_eq!(
+            profiles[0].cache_dirs,
+            vec![
+                firefox.data_dir.join("abc.default-release/cache2"),
+                firefox.cache_dir.join("abc.default-release/cache2"),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_find_chromium_caches_per_profile() {
+        let dir = tempfile::tempdir().unwrap();
+        let chrome = dirs(dir.path(), "Chrome", BrowserFamily::Chromium);
+        for profile in ["Default", "Profile 1"] {
+            fs::create_dir_all(chrome.data_dir.join(profile).join("Code Cache")).unwrap();
+            fs::create_dir_all(chrome.data_dir.join(profile).join("GPUCache")).unwrap();
+            fs::write(chrome.data_dir.join(profile).join("Cookies"), b"secret").unwrap();
+            fs::write(chrome.data_dir.join(profile).join("History"), b"secret").unwrap();
+        }
+        fs::create_dir_all(chrome.cache_dir.join("Default/Cache")).unwrap();
+        fs::create_dir_all(chrome.data_dir.join("System Profile/Cache")).unwrap();
+        fs::write(
+            chrome.data_dir.join("Local State"),
+            r#"{"profile":{"info_cache":{"Profile 1":{"name":"Trabalho"}}}}"#,
+        )
+        .unwrap();
+
+        let profiles = browser_profiles(&chrome);
+        let labels: Vec<&str> = profiles.iter().map(|profile| profile.label.as_str()).collect();
+
+        assert_eq!(labels, vec!["Chrome · Default", "Chrome · Trabalho"]);
+        assert!(profiles[0].cache_dirs.contains(&chrome.cache_dir.join("Default/Cache")));
+        assert_eq!(profiles[1].cache_dirs.len(), 2);
+        assert!(profiles
+            .iter()
+            .flat_map(|profile| &profile.cache_dirs)
+            .all(|dir| CHROMIUM_CACHE_DIRS.iter().any(|name| dir.ends_with(name))));
+    }
+}
@@ -0,0 +1,118 @@
+use crate::domain::value_objects::Ecosystem;
+use super::cargo_artifacts::is_cargo_target;
+use std::path::Path;
+use std::time::SystemTime;
+use walkdir::WalkDir;
+
+/// Regra de detecção: diretórios regeneráveis que só contam como artefato
+/// quando um dos arquivos marcadores do ecossistema está ao lado deles.
+#[derive(Debug)]
+pub(super) struct ArtifactRule {
+    pub(super) ecosystem: Ecosystem,
+    markers: &'static [&'static str],
+    dirs: &'static [&'static str],
+}
+
+/// Tabela de artefatos por ecossistema.
+/// O `target/` do Rust fica na categoria própria de artefatos Rust.
+pub(super) const ARTIFACT_RULES: &[ArtifactRule] = &[
+    ArtifactRule {
+        ecosystem: Ecosystem::Node,
+        markers: &["package.json"],
+        dirs: &["node_modules", "dist", ".next"],
+    },
+    ArtifactRule {
+        ecosystem: Ecosystem::Gradle,
+        markers: &["build.gradle", "build.gradle.kts"],
+        dirs: &["build", ".gradle"],
+    },
+    ArtifactRule {
+        ecosystem: Ecosystem::Python,
+        markers: &["pyproject.toml"],
+        dirs: &["__pycache__", ".tox", ".venv"],
+    },
+    ArtifactRule {
+        ecosystem: Ecosystem::Php,
+        markers: &["composer.json"],
+        dirs: &["vendor"],
+    },
+];
+
+/// Retorna a regra que reconhece `dir` como artefato do projeto em que está.
+pub(super) fn artifact_rule(dir: &Path) -> Option<&'static ArtifactRule> {
+    let name = dir.file_name()?;
+    let project = dir.parent()?;
+
+    ARTIFACT_RULES.iter().find(|rule| {
+        rule.dirs.iter().any(|artifact| name == *artifact)
+            && rule.markers.iter().any(|marker| project.join(marker).is_file())
+    })
+}
+
+/// Última alteração das fontes de um projeto: o arquivo modificado mais
+/// recentemente, lockfile incluído. Artefatos regeneráveis e o `.git` não
+/// contam, pois mudam sem que o projeto seja editado.
+pub(super) fn last_project_activity(project: &Path, max_depth: usize) -> Option<SystemTime> {
+    WalkDir::new(project)
+        .max_depth(max_depth)
+        .follow_links(false)
+        .same_file_system(true)
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.depth() == 0
+                || !entry.file_type().is_dir()
+                || (entry.file_name() != ".git" && artifact_rule(entry.path()).is_none() && !is_cargo_target(entry.path()))
+        })
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| !entry.file_type().is_dir())
+        .filter_map(|entry| entry.metadata().ok()?.modified().ok())
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{self, File};
+    use std::time::Duration;
+
+    #[test]
+    fn should_match_artifact_dirs_next_to_marker_file() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("web")).unwrap();
+        fs::write(dir.path().join("web/package.json"), "{}").unwrap();
+        fs::create_dir_all(dir.path().join("android")).unwrap();
+        fs::write(dir.path().join("android/build.gradle.kts"), "").unwrap();
+        fs::create_dir_all(dir.path().join("docs")).unwrap();
+
+        let ecosystem = |path: &str| artifact_rule(&dir.path().join(path)).map(|rule| rule.ecosystem);
+
+        assert_eq!(ecosystem("web/dist"), Some(Ecosystem::Node));
+        assert_eq!(ecosystem("web/.next"), Some(Ecosystem::Node));
+        assert_eq!(ecosystem("android/build"), Some(Ecosystem::Gradle));
+        assert_eq!(ecosystem("web/build"), None);
+        assert_eq!(ecosystem("docs/dist"), None);
+        assert_eq!(ecosystem("android/vendor"), None);
+    }
+
+    #[test]
+    fn should_ignore_artifacts_when_measuring_project_activity() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = dir.path().join("web");
+        let now = SystemTime::now();
+        let day = Duration::from_secs(24 * 60 * 60);
+        fs::create_dir_all(project.join("src")).unwrap();
+        fs::create_dir_all(project.join("node_modules/pkg")).unwrap();
+        fs::create_dir_all(project.join(".git")).unwrap();
+        for (path, age) in [
+            ("package.json", 90),
+            ("package-lock.json", 40),
+            ("src/index.js", 60),
+            ("node_modules/pkg/index.js", 1),
+            (".git/FETCH_HEAD", 0),
+        ] {
+            File::create(project.join(path)).unwrap().set_modified(now - day * age).unwrap();
+        }
+
+        assert_eq!(last_project_activity(&project, 8), Some(now - day * 40));
+    }
+}
@@ -0,0 +1,63 @@
+use std::fs;
+use std::path::Path;
+use std::time::SystemTime;
+
+/// Partes do `$CARGO_HOME` listadas separadamente, do mais barato ao mais
+/// caro de recuperar: `registry/src` é extraído de novo a partir do
+/// `registry/cache`, enquanto cache e índice precisam ser baixados.
+pub(super) const CARGO_HOME_DIRS: &[&str] = &["registry/src", "registry/cache", "registry/index", "git/checkouts"];
+
+/// Arquivo que o cargo recria a cada build em `target/<perfil>/`.
+const BUILD_LOCK_FILE: &str = ".cargo-lock";
+
+/// Verifica se `path` é o diretório `target` de um projeto Rust.
+pub(super) fn is_cargo_target(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| name == "target") && path.with_file_name("Cargo.toml").is_file()
+}
+
+/// Data do último build: o `.cargo-lock` mais recente entre os perfis
+/// (`debug`, `release`, alvos cruzados) ou, sem ele, o próprio `target`.
+pub(super) fn last_build_time(target: &Path) -> Option<SystemTime> {
+    let lock_times = fs::read_dir(target)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| fs::metadata(entry.path().join(BUILD_LOCK_FILE)).ok())
+        .filter_map(|metadata| metadata.modified().ok());
+
+    lock_times.max().or_else(|| fs::metadata(target).and_then(|metadata| metadata.modified()).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::time::Duration;
+
+    #[test]
+    fn should_recognise_target_next_to_cargo_toml() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("app/target")).unwrap();
+        fs::create_dir_all(dir.path().join("site/target")).unwrap();
+        fs::write(dir.path().join("app/Cargo.toml"), "[package]\n").unwrap();
+
+        assert!(is_cargo_target(&dir.path().join("app/target")));
+        assert!(!is_cargo_target(&dir.path().join("site/target")));
+    }
+
+    #[test]
+    fn should_use_newest_build_lock_as_last_build() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("target");
+        let now = SystemTime::now();
+        for (profile, age) in [("debug", 3600), ("release", 7200)] {
+            fs::create_dir_all(target.join(profile)).unwrap();
+            File::create(target.join(profile).join(BUILD_LOCK_FILE))
+                .unwrap()
+                .set_modified(now - Duration::from_secs(age))
+                .unwrap();
+        }
+
+        assert_eq!(last_build_time(&target), Some(now - Duration::from_secs(3600)));
+    }
+}
@@ -0,0 +1,184 @@
+use serde::Deserialize;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Ferramenta do systemd que lista os core dumps registrados no journal.
+pub(super) const COREDUMPCTL: &str = "coredumpctl";
+
+/// Argumentos que pedem a lista em JSON, sem paginador.
+pub(super) const COREDUMPCTL_LIST_ARGS: &[&str] = &["list", "--json=short", "--no-pager"];
+
+/// Extensões de compressão dos dumps do systemd-coredump.
+const COMPRESSED_EXTENSIONS: &[&str] = &["zst", "xz", "lz4"];
+
+/// `e_type` de um ELF que é core dump.
+const ET_CORE: u16 = 4;
+
+/// Linhas lidas do início de um relatório do apport; o dump vem depois.
+const APPORT_HEADER_LINES: usize = 64;
+
+/// Entrada do `coredumpctl list --json=short`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub(super) struct CoredumpRecord {
+    pub(super) pid: u32,
+    /// Microssegundos desde a época Unix.
+    pub(super) time: u64,
+    pub(super) exe: String,
+}
+
+/// Executável que falhou e quando.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct CrashInfo {
+    pub(super) exe: String,
+    pub(super) crashed_at: Option<SystemTime>,
+}
+
+/// Interpreta a saída do `coredumpctl list --json=short`.
+/// Saída inválida resulta em lista vazia, e os nomes dos arquivos são usados.
+pub(super) fn parse_coredump_list(output: &str) -> Vec<CoredumpRecord> {
+    serde_json::from_str(output).unwrap_or_default()
+}
+
+/// Metadados de um arquivo de falha conhecido: dumps do systemd-coredump
+/// (`core.<comm>.<uid>.<boot>.<pid>.<usec>[.zst]`) e relatórios do apport.
+pub(super) fn crash_info(path: &Path, records: &[CoredumpRecord]) -> Option<CrashInfo> {
+    let name = path.file_name()?.to_string_lossy();
+    if name.ends_with(".crash") {
+        return apport_report_info(path);
+    }
+
+    let (comm, pid, usec) = parse_systemd_dump_name(&name)?;
+    let record = records
+        .iter()
+        .filter(|record| record.pid == pid)
+        .min_by_key(|record| record.time.abs_diff(usec));
+
+    Some(match record {
+        Some(record) => CrashInfo {
+            exe: record.exe.clone(),
+            crashed_at: Some(from_usec(record.time)),
+        },
+        None => CrashInfo {
+            exe: comm.to_string(),
+            crashed_at: Some(from_usec(usec)),
+        },
+    })
+}
+
+/// Separa comando, PID e horário do nome de um dump do systemd-coredump.
+/// O comando pode conter pontos, então os campos são lidos da direita.
+fn parse_systemd_dump_name(name: &str) -> Option<(&str, u32, u64)> {
+    let name = match name.rsplit_once('.') {
+        Some((rest, extension)) if COMPRESSED_EXTENSIONS.contains(&extension) => rest,
+        _ => name,
+    };
+
+    let mut fields = name.rsplitn(5, '.');
+    let usec = fields.next()?.parse().ok()?;
+    let pid = fields.next()?.parse().ok()?;
+    let _boot_id = fields.next()?;
+    let _uid: u32 = fields.next()?.parse().ok()?;
+    let comm = fields.next()?.strip_prefix("core.")?;
+
+    Some((comm, pid, usec))
+}
+
+/// Lê o `ExecutablePath` do cabeçalho de um relatório `.crash` do apport.
+fn apport_report_info(path: &Path) -> Option<CrashInfo> {
+    let file = File::open(path).ok()?;
+    let exe = BufReader::new(file)
+        .lines()
+        .take(APPORT_HEADER_LINES)
+        .map_while(|line| line.ok())
+        .find_map(|line| line.strip_prefix("ExecutablePath: ").map(str::to_string))?;
+
+    let crashed_at = fs::metadata(path).and_then(|metadata| metadata.modified()).ok();
+    Some(CrashInfo { exe, crashed_at })
+}
+
+/// Verifica se é um `core` ou `core.<pid>` solto, confirmando pelo cabeçalho
+/// ELF que o arquivo é mesmo um core dump e não algo que só tem esse nome.
+pub(super) fn is_core_file(path: &Path) -> bool {
+    let is_core_name = path.file_name().and_then(|name| name.to_str()).is_some_and(|name| {
+        name == "core"
+            || name
+                .strip_prefix("core.")
+                .is_some_and(|pid| !pid.is_empty() && pid.bytes().all(|byte| byte.is_ascii_digit()))
+    });
+    if !is_core_name {
+        return false;
+    }
+
+    let mut header = [0u8; 18];
+    let read = File::open(path).and_then(|mut file| file.read_exact(&mut header));
+    if read.is_err() || &header[..4] != b"\x7fELF" {
+        return false;
+    }
+
+    // EI_DATA: 1 = little-endian, 2 = big-endian
+    let e_type = match header[5] {
+        1 => u16::from_le_bytes([header[16], header[17]]),
+        2 => u16::from_be_bytes([header[16], header[17]]),
+        _ => return false,
+    };
+    e_type == ET_CORE
+}
+
+fn from_usec(usec: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_micros(usec)
+}
+
+/// Cabeçalho ELF mínimo de um core dump little-endian, para os testes.
+#[cfg(test)]
+pub(super) fn elf_core_header() -> Vec<u8> {
+    let mut header = b"\x7fELF\x02\x01\x01".to_vec();
+    header.resize(16, 0);
+    header.extend_from_slice(&ET_CORE.to_le_bytes());
+    header
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_match_systemd_dumps_with_coredumpctl_records() {
+        let records = parse_coredump_list(
+            r#"[{"time":1700000000000000,"pid":4242,"uid":1000,"gid":1000,"sig":11,"corefile":"present","exe":"/usr/bin/my.app","size":2048}]"#,
+        );
+        assert_eq!(records.len(), 1);
+
+        let known = crash_info(Path::new("/var/lib/systemd/coredump/core.my\\x2eapp.1000.9f2c.4242.1700000000000123.zst"), &records).unwrap();
+        assert_eq!(known.exe, "/usr/bin/my.app");
+        assert_eq!(known.crashed_at, Some(from_usec(1_700_000_000_000_000)));
+
+        let unknown = crash_info(Path::new("/var/lib/systemd/coredump/core.node.1000.9f2c.77.1600000000000000.zst"), &records).unwrap();
+        assert_eq!(unknown.exe, "node");
+        assert_eq!(unknown.crashed_at, Some(from_usec(1_600_000_000_000_000)));
+
+        assert!(parse_coredump_list("No coredumps found.").is_empty());
+    }
+
+    #[test]
+    fn should_read_executable_of_apport_report() {
+        let dir = tempfile::tempdir().unwrap();
+        let report = dir.path().join("_usr_bin_foo.1000.crash");
+        fs::write(&report, "ProblemType: Crash\nDate: Mon Jan  1 10:00:00 2024\nExecutablePath: /usr/bin/foo\nCoreDump: base64\n").unwrap();
+
+        assert_eq!(crash_info(&report, &[]).unwrap().exe, "/usr/bin/foo");
+    }
+
+    #[test]
+    fn should_recognise_only_real_core_files() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("core.1234"), elf_core_header()).unwrap();
+        fs::write(dir.path().join("core"), "#!/bin/sh\necho script chamado core\n").unwrap();
+        fs::write(dir.path().join("core.rs"), elf_core_header()).unwrap();
+
+        assert!(is_core_file(&dir.path().join("core.1234")));
+        assert!(!is_core_file(&dir.path().join("core")));
+        assert!(!is_core_file(&dir.path().join("core.rs")));
+    }
+}
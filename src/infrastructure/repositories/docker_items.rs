@@ -0,0 +1,6 @@
+/// Prefixos dos rótulos exibidos para os itens Docker.
+/// O ID usado na remoção fica nos detalhes do item, não no rótulo.
+pub(super) const IMAGE_LABEL: &str = "Docker Image: ";
+pub(super) const VOLUME_LABEL: &str = "Docker Volume: ";
+pub(super) const BUILD_CACHE_LABEL: &str = "Docker Build Cache: ";
+pub(super) const CONTAINER_LABEL: &str = "Docker Container: ";
@@ -0,0 +1,249 @@
+use crate::domain::entities::CleanableItem;
+use crate::domain::value_objects::ItemDetails;
+use super::scan_context::{DiskUsage, ScanContext, item_from_metadata};
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::hash::{DefaultHasher, Hasher};
+use std::io::{self, Read};
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+
+/// Quantidade de bytes lidos no início do arquivo para o hash parcial.
+const PARTIAL_HASH_BYTES: u64 = 4096;
+
+/// Tamanho do buffer de leitura do hash completo.
+const READ_BUFFER_BYTES: usize = 64 * 1024;
+
+/// Busca arquivos com conteúdo idêntico.
+/// Agrupa por tamanho, depois por hash parcial e por fim pelo hash do conteúdo completo.
+pub(super) struct DuplicateFileFinder<'a> {
+    roots: &'a [PathBuf],
+    max_depth: usize,
+    min_size: u64,
+}
+
+impl<'a> DuplicateFileFinder<'a> {
+    pub(super) fn new(roots: &'a [PathBuf], max_depth: usize, min_size: u64) -> Self {
+        Self {
+            roots,
+            max_depth,
+            min_size: min_size.max(1),
+        }
+    }
+
+    /// Retorna os itens de cada grupo de duplicados.
+    /// O primeiro caminho (em ordem alfabética) é mantido como original;
+    /// os demais são marcados como cópias removíveis.
+    pub(super) fn find(&self, ctx: &ScanContext) -> Vec<CleanableItem> {
+        let mut items = Vec::new();
+
+        for (_, group) in self.group_by_size(ctx) {
+            for group in Self::refine(group, |path| Self::hash_file(path, Some(PARTIAL_HASH_BYTES))) {
+                for mut group in Self::refine(group, |path| Self::hash_file(path, None)) {
+                    if !ctx.is_active() {
+                        return items;
+                    }
+
+                    group.sort();
+                    let original = group[0].to_string_lossy().to_string();
+
+                    for (index, path) in group.iter().enumerate() {
+                        let Ok(metadata) = fs::metadata(path) else {
+                            continue;
+                        };
+                        // Hardlinks já foram descartados, então os blocos não se repetem
+                        let item = item_from_metadata(path, &metadata, DiskUsage::of(&metadata));
+
+                        if index == 0 {
+                            items.push(item.with_details(ItemDetails::DuplicateOriginal));
+                        } else {
+                            ctx.item_found(item.size_in_bytes());
+                            items.push(item.with_details(ItemDetails::DuplicateCopy { original: original.clone() }));
+                        }
+                    }
+                }
+            }
+        }
+
+        items
+    }
+
+    /// Agrupa os arquivos das raízes por tamanho, mantendo só tamanhos repetidos.
+    /// Hardlinks do mesmo inode contam uma única vez, pois não ocupam espaço extra.
+    fn group_by_size(&self, ctx: &ScanContext) -> Vec<(u64, Vec<PathBuf>)> {
+        let mut by_size: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+        let mut seen_inodes = HashSet::new();
+
+        for root in self.roots {
+            if !root.exists() {
+                continue;
+            }
+
+            // Links simbólicos não são cópias: apagá-los não libera o conteúdo
+            for entry in ctx.files(root, self.max_depth).filter(|e| e.file_type().is_file()) {
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+
+                if metadata.len() < self.min_size || !seen_inodes.insert((metadata.dev(), metadata.ino())) {
+                    continue;
+                }
+
+                by_size.entry(metadata.len()).or_default().push(entry.into_path());
+            }
+        }
+
+        let mut groups: Vec<_> = by_size.into_iter().filter(|(_, paths)| paths.len() > 1).collect();
+        groups.sort_by_key(|(size, _)| std::cmp::Reverse(*size));
+        groups
+    }
+
+    /// Divide um grupo pela chave calculada, descartando subgrupos sem repetição
+    /// e arquivos que não puderam ser lidos.
+    fn refine<F>(group: Vec<PathBuf>, key: F) -> Vec<Vec<PathBuf>>
+    where
+        F: Fn(&Path) -> io::Result<u64>,
+    {
+        let mut by_key: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+        for path in group {
+            if let Ok(hash) = key(&path) {
+                by_key.entry(hash).or_default().push(path);
+            }
+        }
+
+        by_key.into_values().filter(|paths| paths.len() > 1).collect()
+    }
+
+    /// Calcula o hash dos primeiros `limit` bytes, ou do arquivo inteiro.
+    fn hash_file(path: &Path, limit: Option<u64>) -> io::Result<u64> {
+        let file = File::open(path)?;
+        let mut reader: Box<dyn Read> = match limit {
+            Some(limit) => Box::new(file.take(limit)),
+            None => Box::new(file),
+        };
+
+        let mut hasher = DefaultHasher::new();
+        let mut buffer = vec![0u8; READ_BUFFER_BYTES];
+        loop {
+            let read = reader.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            hasher.write(&buffer[..read]);
+        }
+
+        Ok(hasher.finish())
+    }
+}
+
+/// Verifica se dois arquivos têm exatamente o mesmo conteúdo.
+pub(crate) fn same_content(a: &Path, b: &Path) -> io::Result<bool> {
+    if a.metadata()?.len() != b.metadata()?.len() {
+        return Ok(false);
+    }
+
+    let mut a = File::open(a)?;
+    let mut b = File::open(b)?;
+    let mut buffer_a = vec![0u8; READ_BUFFER_BYTES];
+    let mut buffer_b = vec![0u8; READ_BUFFER_BYTES];
+
+    loop {
+        let read = a.read(&mut buffer_a)?;
+        if read == 0 {
+            return Ok(b.read(&mut buffer_b)? == 0);
+        }
+
+        b.read_exact(&mut buffer_b[..read])?;
+        if buffer_a[..read] != buffer_b[..read] {
+            return Ok(false);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::scan_context::ScanTotals;
+    use crate::domain::repositories::NoScanProgress;
+    use crate::domain::value_objects::{CancellationToken, CategoryType};
+    use crate::infrastructure::config::ExclusionRules;
+    use std::fs;
+
+    fn find_in(root: &Path, min_size: u64) -> Vec<CleanableItem> {
+        let totals = ScanTotals::default();
+        let cancellation = CancellationToken::new();
+        let exclusions = ExclusionRules::default();
+        let ctx = ScanContext::new(
+            CategoryType::DuplicateFiles,
+            &NoScanProgress,
+            &totals,
+            &cancellation,
+            &exclusions,
+        );
+        let roots = vec![root.to_path_buf()];
+
+        DuplicateFileFinder::new(&roots, 10, min_size).find(&ctx)
+    }
+
+    #[test]
+    fn should_keep_one_original_per_duplicate_group() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("assets/copy")).unwrap();
+        fs::write(dir.path().join("assets/a.png"), b"same image bytes").unwrap();
+        fs::write(dir.path().join("assets/copy/a.png"), b"same image bytes").unwrap();
+        fs::write(dir.path().join("assets/z.png"), b"same image bytes").unwrap();
+        fs::write(dir.path().join("assets/other.png"), b"different bytes!").unwrap();
+
+        let items = find_in(dir.path(), 1);
+
+        assert_eq!(items.len(), 3);
+        let original: Vec<_> = items.iter().filter(|item| !item.is_reclaimable()).collect();
+        assert_eq!(original.len(), 1);
+        assert!(original[0].path().ends_with("assets/a.png"));
+        assert!(items
+            .iter()
+            .filter(|item| item.is_reclaimable())
+            .all(|item| item.original_path() == Some(original[0].path())));
+    }
+
+    #[test]
+    fn should_ignore_files_that_only_share_size_or_prefix() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut first = vec![7u8; PARTIAL_HASH_BYTES as usize + 10];
+        let second = first.clone();
+        *first.last_mut().unwrap() = 8;
+        fs::write(dir.path().join("first.bin"), &first).unwrap();
+        fs::write(dir.path().join("second.bin"), &second).unwrap();
+
+        assert!(find_in(dir.path(), 1).is_empty());
+    }
+
+    #[test]
+    fn should_not_report_hardlinks_as_duplicates() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a.bin"), b"linked content").unwrap();
+        fs::hard_link(dir.path().join("a.bin"), dir.path().join("b.bin")).unwrap();
+
+        assert!(find_in(dir.path(), 1).is_empty());
+    }
+
+    #[test]
+    fn should_skip_files_below_minimum_size() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a.txt"), b"tiny").unwrap();
+        fs::write(dir.path().join("b.txt"), b"tiny").unwrap();
+
+        assert!(find_in(dir.path(), 1024).is_empty());
+    }
+
+    #[test]
+    fn should_compare_file_contents() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a"), b"abc").unwrap();
+        fs::write(dir.path().join("b"), b"abc").unwrap();
+        fs::write(dir.path().join("c"), b"abd").unwrap();
+
+        assert!(same_content(&dir.path().join("a"), &dir.path().join("b")).unwrap());
+        assert!(!same_content(&dir.path().join("a"), &dir.path().join("c")).unwrap());
+    }
+}
@@ -1,61 +1,309 @@
 use crate::domain::repositories::CleanerRepository;
-use crate::domain::entities::CleanableCategory;
+use crate::domain::entities::{CleanableCategory, CleanableItem, CleanReport};
+use crate::domain::value_objects::{CategoryType, CancellationToken, ItemKind};
+use crate::infrastructure::command::{CommandRunner, default_command_runner};
+use crate::infrastructure::docker::DockerClient;
+use crate::infrastructure::config::{ExclusionRules, JournalConfig};
+use super::duplicate_file_finder::same_content;
+use super::trash::forget_trash_entry;
+use super::journal::{JOURNALCTL, vacuum_args};
+use super::app_runtimes::uninstall_command;
+use super::open_files::{OpenFiles, describe_holders};
+use super::scan_context::DiskUsage;
 use anyhow::Result;
+use walkdir::WalkDir;
 use std::fs;
-use std::path::Path;
-use std::process::Command;
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// O que sobrou de um diretório limpo só em parte.
+#[derive(Debug, Default)]
+struct Leftover {
+    freed_bytes: u64,
+    protected: Vec<PathBuf>,
+}
+
+impl Leftover {
+    /// Junta o resultado da remoção de mais uma parte de um item composto.
+    /// As partes seguintes são removidas mesmo que uma anterior tenha falhado.
+    fn combine(acc: Result<Option<Leftover>>, next: Result<Option<Leftover>>) -> Result<Option<Leftover>> {
+        match (acc?, next?) {
+            (None, None) => Ok(None),
+            (Some(leftover), None) | (None, Some(leftover)) => Ok(Some(leftover)),
+            (Some(mut acc), Some(next)) => {
+                acc.freed_bytes += next.freed_bytes;
+                acc.protected.extend(next.protected);
+                Ok(Some(acc))
+            }
+        }
+    }
+}
 
 /// Implementação concreta do CleanerRepository.
 /// Realiza operações de limpeza no sistema de arquivos e serviços.
-pub struct FileSystemCleanerRepository;
+pub struct FileSystemCleanerRepository {
+    current_uid: u32,
+    exclusions: ExclusionRules,
+    commands: Arc<dyn CommandRunner>,
+    docker: DockerClient,
+    journal: JournalConfig,
+    same_file_system: bool,
+}
 
 impl FileSystemCleanerRepository {
     pub fn new() -> Self {
-        Self
+        // SAFETY: geteuid não tem pré-condições e nunca falha
+        let current_uid = unsafe { libc::geteuid() };
+        Self {
+            current_uid,
+            exclusions: ExclusionRules::default(),
+            commands: default_command_runner(),
+            docker: DockerClient::from_env(),
+            journal: JournalConfig::default(),
+            same_file_system: true,
+        }
+    }
+
+    /// Define os caminhos que nunca podem ser removidos.
+    pub fn with_exclusions(mut self, exclusions: ExclusionRules) -> Self {
+        self.exclusions = exclusions;
+        self
+    }
+
+    /// Define os limites aplicados ao reduzir o journal do systemd.
+    pub fn with_journal(mut self, journal: JournalConfig) -> Self {
+        self.journal = journal;
+        self
+    }
+
+    /// Permite entrar em outros sistemas de arquivos montados abaixo dos
+    /// diretórios, como a varredura faz com a mesma opção.
+    pub fn with_same_file_system(mut self, same_file_system: bool) -> Self {
+        self.same_file_system = same_file_system;
+        self
     }
 
-    /// Limpa um arquivo individual.
-    fn clean_file(&self, path: &Path) -> Result<()> {
-        if path.exists() && path.is_file() {
+    /// Substitui o executor das ferramentas externas.
+    #[allow(dead_code)]
+    pub fn with_command_runner(mut self, commands: Arc<dyn CommandRunner>) -> Self {
+        self.commands = commands;
+        self
+    }
+
+    /// Substitui o cliente da API do Docker.
+    #[allow(dead_code)]
+    pub fn with_docker(mut self, docker: DockerClient) -> Self {
+        self.docker = docker;
+        self
+    }
+
+    /// Define o usuário efetivo considerado na regra de dono.
+    #[allow(dead_code)]
+    pub fn with_uid(mut self, uid: u32) -> Self {
+        self.current_uid = uid;
+        self
+    }
+
+    /// Itens do sistema de arquivos de outro usuário só são removidos pelo root.
+    fn owned_by_current_user(&self, item: &CleanableItem) -> bool {
+        if self.current_uid == 0 || !item.kind().is_filesystem() {
+            return true;
+        }
+
+        item.owner_uid().is_none_or(|owner| owner == self.current_uid)
+    }
+
+    /// Limpa um arquivo individual ou um link simbólico (nunca o alvo do link).
+    fn clean_file(&self, path: &Path) -> Result<Option<Leftover>> {
+        if fs::symlink_metadata(path).is_ok_and(|metadata| !metadata.is_dir()) {
             fs::remove_file(path)?;
         }
-        Ok(())
+        Ok(None)
+    }
+
+    /// Limpa um diretório, preservando o conteúdo protegido pelas exclusões.
+    /// Links internos são removidos sem seguir o alvo e, como na varredura,
+    /// outros sistemas de arquivos montados abaixo dele só são tocados se a
+    /// configuração permitir.
+    /// Quando algo preservado ou o cancelamento impede a remoção do próprio
+    /// diretório, retorna o que foi liberado e o que ficou; falha se nada
+    /// explicar o que sobrou.
+    fn clean_directory(&self, path: &Path, cancellation: &CancellationToken) -> Result<Option<Leftover>> {
+        if !fs::symlink_metadata(path).is_ok_and(|metadata| metadata.is_dir()) {
+            return Ok(None);
+        }
+
+        // `contents_first` não poda a árvore, então a ordem é invertida depois
+        let mut protected = Vec::new();
+        let entries: Vec<_> = WalkDir::new(path)
+            .follow_links(false)
+            .same_file_system(self.same_file_system)
+            .into_iter()
+            .filter_entry(|entry| {
+                let excluded = self.exclusions.matches(entry.path(), entry.file_type().is_dir());
+                if excluded {
+                    protected.push(entry.path().to_path_buf());
+                }
+                !excluded
+            })
+            .take_while(|_| !cancellation.is_cancelled())
+            .filter_map(|e| e.ok())
+            .collect();
+
+        // Interrompido antes de remover qualquer coisa
+        if cancellation.is_cancelled() {
+            return Err(anyhow::anyhow!("limpeza de {} interrompida", path.display()));
+        }
+
+        let mut freed_bytes = 0;
+        let mut interrupted = false;
+        for entry in entries.iter().rev() {
+            if cancellation.is_cancelled() {
+                interrupted = true;
+                break;
+            }
+
+            // Diretórios com conteúdo preservado não ficam vazios e são mantidos;
+            // pontos de montagem também falham aqui, pois não estão vazios
+            let usage = entry.metadata().map(|metadata| DiskUsage::of(&metadata)).unwrap_or_default();
+            let removed = if entry.file_type().is_dir() {
+                fs::remove_dir(entry.path())
+            } else {
+                fs::remove_file(entry.path())
+            };
+            if removed.is_ok() {
+                freed_bytes += usage.on_disk;
+            }
+        }
+
+        if !path.exists() {
+            return Ok(None);
+        }
+        if protected.is_empty() && !interrupted {
+            return Err(anyhow::anyhow!("{} não pôde ser removido por completo", path.display()));
+        }
+        Ok(Some(Leftover { freed_bytes, protected }))
     }
 
-    /// Limpa um diretório completo.
-    fn clean_directory(&self, path: &Path) -> Result<()> {
-        if path.exists() && path.is_dir() {
-            fs::remove_dir_all(path)?;
+    /// Esvazia um log ainda em uso sem removê-lo: o processo que o mantém
+    /// aberto continua escrevendo no mesmo arquivo e o espaço é liberado na hora.
+    fn truncate_file(&self, path: &Path) -> Result<()> {
+        if self.exclusions.is_excluded(path) {
+            return Err(anyhow::anyhow!("{} está protegido", path.display()));
+        }
+        if !fs::symlink_metadata(path)?.is_file() {
+            return Err(anyhow::anyhow!("{} não é um arquivo comum", path.display()));
         }
+
+        fs::OpenOptions::new()
+            .write(true)
+            .custom_flags(libc::O_NOFOLLOW)
+            .open(path)?
+            .set_len(0)?;
         Ok(())
     }
 
-    /// Limpa imagens Docker usando comando CLI.
-    fn clean_docker_images(&self) -> Result<usize> {
-        let output = Command::new("docker")
-            .args(&["image", "prune", "-f"])
-            .output()?;
+    /// Remove um arquivo, link ou diretório respeitando as exclusões.
+    /// O tipo vem do próprio caminho, sem seguir links.
+    fn remove_path(&self, path: &Path, cancellation: &CancellationToken) -> Result<Option<Leftover>> {
+        if self.exclusions.is_excluded(path) {
+            return Err(anyhow::anyhow!("{} está protegido", path.display()));
+        }
 
-        if output.status.success() {
-            // Parse do output para contar itens removidos
-            let _stdout = String::from_utf8_lossy(&output.stdout);
-            // Docker retorna algo como "Total reclaimed space: X"
-            Ok(1) // Simplificado - em produção parsearia o output
+        if fs::symlink_metadata(path)?.is_dir() {
+            self.clean_directory(path, cancellation)
         } else {
-            Ok(0)
+            self.clean_file(path)
+        }
+    }
+
+    /// Reduz o journal do systemd com `journalctl --vacuum-*`.
+    /// O próprio journalctl nunca remove o arquivo ativo.
+    fn clean_system_journal(&self) -> Result<()> {
+        let args = vacuum_args(&self.journal);
+        if args.is_empty() {
+            return Err(anyhow::anyhow!("Nenhum limite de limpeza do journal configurado"));
+        }
+
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = self.commands.run(JOURNALCTL, &args)?;
+        if !output.success {
+            return Err(anyhow::anyhow!("{} falhou: {}", JOURNALCTL, output.stderr.trim()));
+        }
+        Ok(())
+    }
+
+    /// Remove um objeto Docker pelo ID completo (ou nome, para volumes) guardado na varredura.
+    /// O que surgiu depois da varredura nunca é tocado.
+    fn remove_docker_item(&self, item: &CleanableItem) -> Result<()> {
+        let id = item.docker_id().ok_or_else(|| anyhow::anyhow!("{} não é um item Docker", item.path()))?;
+
+        match item.kind() {
+            ItemKind::DockerImage => self.docker.remove_image(id)?,
+            ItemKind::DockerVolume => self.docker.remove_volume(id)?,
+            ItemKind::DockerBuildCache => {
+                let pruned = self.docker.prune_build_cache(id)?;
+                if !pruned.deleted.iter().any(|deleted| deleted == id) {
+                    return Err(anyhow::anyhow!("registro {} em uso ou já removido", id));
+                }
+            }
+            _ => self.docker.remove_container(id)?,
+        }
+        Ok(())
+    }
+
+    /// Remove um runtime Flatpak ou uma revisão Snap pela ferramenta de origem.
+    fn uninstall_runtime(&self, item: &CleanableItem) -> Result<()> {
+        let (program, args) = uninstall_command(item)
+            .ok_or_else(|| anyhow::anyhow!("{} não é um runtime conhecido", item.path()))?;
+
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = self.commands.run(program, &args)?;
+        if !output.success {
+            return Err(anyhow::anyhow!("{} falhou: {}", program, output.stderr.trim()));
         }
+        Ok(())
     }
 
-    /// Limpa volumes Docker usando comando CLI.
-    fn clean_docker_volumes(&self) -> Result<usize> {
-        let output = Command::new("docker")
-            .args(&["volume", "prune", "-f"])
-            .output()?;
+    /// Remove uma cópia duplicada somente se o original ainda existir com o mesmo conteúdo,
+    /// garantindo que a última cópia de um grupo nunca seja apagada.
+    fn clean_duplicate(&self, path: &Path, original: &Path) -> Result<Option<Leftover>> {
+        if path == original || !original.is_file() || !same_content(path, original)? {
+            return Err(anyhow::anyhow!("Original de {} não encontrado", path.display()));
+        }
+
+        self.clean_file(path)
+    }
 
-        if output.status.success() {
-            Ok(1)
+    /// Descreve os processos que usam o item (ou algo dentro dele), se houver.
+    fn in_use_reason(open_files: &OpenFiles, item: &CleanableItem) -> Option<String> {
+        let mut holders: Vec<_> = if item.parts().is_empty() {
+            open_files.holders_under(Path::new(item.path()))
         } else {
-            Ok(0)
+            item.parts().iter().flat_map(|part| open_files.holders_under(Path::new(part))).collect()
+        };
+        // Um processo pode segurar vários arquivos do item e o snapshot não vem ordenado
+        holders.sort_by_key(|holder| holder.pid);
+        holders.dedup_by_key(|holder| holder.pid);
+
+        (!holders.is_empty()).then(|| describe_holders(&holders))
+    }
+
+    /// Determina o tipo de limpeza de uma categoria.
+    /// Usa o tipo da categoria quando conhecido e o nome como alternativa.
+    fn strategy_for(&self, category: &CleanableCategory) -> CleanupStrategy {
+        match category.category_type() {
+            Some(CategoryType::DockerImages) => CleanupStrategy::DockerImages,
+            Some(CategoryType::DockerVolumes) => CleanupStrategy::DockerVolumes,
+            Some(CategoryType::DuplicateFiles) => CleanupStrategy::DuplicateFiles,
+            Some(CategoryType::Trash) => CleanupStrategy::Trash,
+            Some(CategoryType::SystemJournal) => CleanupStrategy::SystemJournal,
+            Some(CategoryType::UnusedRuntimes) => CleanupStrategy::UnusedRuntimes,
+            Some(CategoryType::DockerBuildCache) => CleanupStrategy::DockerBuildCache,
+            Some(CategoryType::DockerContainers) => CleanupStrategy::DockerContainers,
+            Some(_) => CleanupStrategy::FileSystem,
+            None => self.get_cleanup_strategy(category.name()),
         }
     }
 
@@ -78,40 +326,135 @@ enum CleanupStrategy {
     FileSystem,
     DockerImages,
     DockerVolumes,
+    DuplicateFiles,
+    Trash,
+    SystemJournal,
+    UnusedRuntimes,
+    DockerBuildCache,
+    DockerContainers,
 }
 
 impl CleanerRepository for FileSystemCleanerRepository {
-    fn clean_category(&self, category: &CleanableCategory) -> Result<usize> {
-        let strategy = self.get_cleanup_strategy(category.name());
-        let mut cleaned_count = 0;
+    fn clean_category(&self, category: &CleanableCategory, cancellation: &CancellationToken) -> Result<CleanReport> {
+        let strategy = self.strategy_for(category);
+        let mut report = CleanReport::new();
+
+        if cancellation.is_cancelled() {
+            report.mark_cancelled();
+            return Ok(report);
+        }
 
         match strategy {
-            CleanupStrategy::DockerImages => {
-                cleaned_count += self.clean_docker_images()?;
+            CleanupStrategy::SystemJournal => {
+                self.clean_system_journal()?;
+                for item in category.items() {
+                    report.record_removed(item.path().to_string());
+                }
             }
-            CleanupStrategy::DockerVolumes => {
-                cleaned_count += self.clean_docker_volumes()?;
+            CleanupStrategy::DockerImages
+            | CleanupStrategy::DockerVolumes
+            | CleanupStrategy::DockerBuildCache
+            | CleanupStrategy::DockerContainers => {
+                // Só os IDs listados são removidos, um a um
+                for item in category.items() {
+                    if cancellation.is_cancelled() {
+                        report.mark_cancelled();
+                        break;
+                    }
+
+                    match self.remove_docker_item(item) {
+                        Ok(()) => report.record_removed(item.path().to_string()),
+                        Err(e) => report.record_skipped(item.path().to_string(), e.to_string()),
+                    }
+                }
+            }
+            CleanupStrategy::UnusedRuntimes => {
+                // Cada item é removido sozinho, para que uma falha não afete os demais
+                for item in category.items() {
+                    if cancellation.is_cancelled() {
+                        report.mark_cancelled();
+                        break;
+                    }
+
+                    match self.uninstall_runtime(item) {
+                        Ok(()) => report.record_removed(item.path().to_string()),
+                        Err(e) => report.record_skipped(item.path().to_string(), e.to_string()),
+                    }
+                }
             }
-            CleanupStrategy::FileSystem => {
+            CleanupStrategy::FileSystem | CleanupStrategy::DuplicateFiles | CleanupStrategy::Trash => {
+                let open_files = OpenFiles::snapshot();
+
                 for item in category.items() {
+                    if cancellation.is_cancelled() {
+                        report.mark_cancelled();
+                        break;
+                    }
+
+                    // Itens mantidos (ex.: originais de duplicados) nunca são removidos
+                    if !item.is_reclaimable() {
+                        continue;
+                    }
+                    if !self.owned_by_current_user(item) {
+                        report.record_skipped(item.path().to_string(), "pertence a outro usuário".to_string());
+                        continue;
+                    }
+
+                    // Logs ativos estão abertos por definição e são truncados, não removidos
+                    let in_use = open_files.as_ref().filter(|_| !item.truncates_in_place());
+                    if let Some(reason) = in_use.and_then(|open_files| Self::in_use_reason(open_files, item)) {
+                        report.record_skipped(item.path().to_string(), reason);
+                        continue;
+                    }
+
                     let path = Path::new(item.path());
-                    
-                    let result = if path.is_file() {
-                        self.clean_file(path)
-                    } else if path.is_dir() {
-                        self.clean_directory(path)
+                    let result = if !item.parts().is_empty() {
+                        // Itens compostos (ex.: caches de um perfil) removem cada parte
+                        item.parts()
+                            .iter()
+                            .map(|part| self.remove_path(Path::new(part), cancellation))
+                            .fold(Ok(None), Leftover::combine)
+                    } else if item.truncates_in_place() {
+                        self.truncate_file(path).map(|_| None)
+                    } else if strategy == CleanupStrategy::DuplicateFiles {
+                        match item.original_path() {
+                            _ if self.exclusions.is_excluded(path) => {
+                                report.record_skipped(item.path().to_string(), "excluído pelas regras".to_string());
+                                continue;
+                            }
+                            Some(original) => self.clean_duplicate(path, Path::new(original)),
+                            None => Err(anyhow::anyhow!("Original de {} não encontrado", path.display())),
+                        }
                     } else {
-                        continue;
+                        self.remove_path(path, cancellation)
                     };
 
-                    if result.is_ok() {
-                        cleaned_count += 1;
+                    // Na lixeira, o `.trashinfo` e o `directorysizes` só mudam depois do conteúdo
+                    let result = result.and_then(|leftover| match (&strategy, &leftover) {
+                        (CleanupStrategy::Trash, None) => forget_trash_entry(path).map(|_| leftover),
+                        _ => Ok(leftover),
+                    });
+
+                    match result {
+                        Ok(None) => report.record_removed(item.path().to_string()),
+                        Ok(Some(leftover)) => {
+                            report.record_partial(item.path().to_string(), leftover.freed_bytes);
+                            for kept in leftover.protected {
+                                report.record_skipped(kept.to_string_lossy().to_string(), "protegido pelas exclusões".to_string());
+                            }
+                        }
+                        Err(e) => report.record_skipped(item.path().to_string(), e.to_string()),
                     }
                 }
+
+                // Um diretório interrompido no meio também conta como cancelamento
+                if cancellation.is_cancelled() {
+                    report.mark_cancelled();
+                }
             }
         }
 
-        Ok(cleaned_count)
+        Ok(report)
     }
 
     fn can_clean(&self, category: &CleanableCategory) -> bool {
@@ -129,7 +472,9 @@ impl Default for FileSystemCleanerRepository {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::entities::CleanableItem;
+    use crate::domain::value_objects::{ItemDetails, LogState};
+    use crate::infrastructure::command::command_runner::{SystemCommandRunner, fake_program};
+    use crate::infrastructure::docker::docker_client::FakeDockerDaemon;
 
     #[test]
     fn should_identify_docker_strategy() {
@@ -140,6 +485,231 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_remove_only_listed_docker_images_and_volumes() {
+        let dir = tempfile::tempdir().unwrap();
+        let daemon = FakeDockerDaemon::start(
+            dir.path(),
+            &[
+                ("DELETE /images/sha256%3Aaaaabbbbccccdddd", 200, r#"[{"Deleted":"sha256:aaaabbbbccccdddd"}]"#),
+                ("DELETE /images/sha256%3Aeeeeffff00001111", 409, r#"{"message":"image is being used by stopped container 3f2a"}"#),
+                ("DELETE /volumes/dados", 204, ""),
+            ],
+        );
+        let repo = FileSystemCleanerRepository::new().with_docker(daemon.client.clone());
+
+        let mut images = CleanableCategory::for_type(CategoryType::DockerImages);
+        // O rótulo traz o ID curto; a remoção usa o completo guardado nos detalhes
+        for id in ["sha256:aaaabbbbccccdddd", "sha256:eeeeffff00001111"] {
+            images.add_item(
+                CleanableItem::new(format!("Docker Image: {}", &id[7..19]), 1024)
+                    .with_kind(ItemKind::DockerImage)
+                    .with_details(ItemDetails::Docker { id: id.to_string() }),
+            );
+        }
+        let report = repo.clean_category(&images, &CancellationToken::new()).unwrap();
+        assert_eq!(report.removed(), ["Docker Image: aaaabbbbcccc"]);
+        assert!(report.skipped()[0].reason.contains("being used by stopped container"));
+
+        let mut volumes = CleanableCategory::for_type(CategoryType::DockerVolumes);
+        volumes.add_item(
+            CleanableItem::new("Docker Volume: dados".to_string(), 1)
+                .with_unknown_size()
+                .with_kind(ItemKind::DockerVolume)
+                .with_details(ItemDetails::Docker { id: "dados".to_string() }),
+        );
+        let report = repo.clean_category(&volumes, &CancellationToken::new()).unwrap();
+        assert_eq!(report.removed(), ["Docker Volume: dados"]);
+
+        assert_eq!(
+            daemon.requests(),
+            [
+                "DELETE /images/sha256%3Aaaaabbbbccccdddd",
+                "DELETE /images/sha256%3Aeeeeffff00001111",
+                "DELETE /volumes/dados",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_remove_only_listed_containers_and_build_cache_records() {
+        let dir = tempfile::tempdir().unwrap();
+        let daemon = FakeDockerDaemon::start(
+            dir.path(),
+            &[
+                ("DELETE /containers/3f2a1b4c5d6e7f80", 204, ""),
+                ("DELETE /containers/9e8d7c6b5a49382a", 409, r#"{"message":"container is running: stop it first"}"#),
+                ("POST /build/prune", 200, r#"{"CachesDeleted":[],"SpaceReclaimed":0}"#),
+            ],
+        );
+        let repo = FileSystemCleanerRepository::new().with_docker(daemon.client.clone());
+
+        let mut containers = CleanableCategory::for_type(CategoryType::DockerContainers);
+        for (id, name) in [("3f2a1b4c5d6e7f80", "web-old"), ("9e8d7c6b5a49382a", "db")] {
+            containers.add_item(
+                CleanableItem::new(format!("Docker Container: {} ({})", &id[..12], name), 1024)
+                    .with_kind(ItemKind::DockerContainer)
+                    .with_details(ItemDetails::Docker { id: id.to_string() }),
+            );
+        }
+        let report = repo.clean_category(&containers, &CancellationToken::new()).unwrap();
+        assert_eq!(report.removed(), ["Docker Container: 3f2a1b4c5d6e (web-old)"]);
+        assert!(report.skipped()[0].reason.contains("container is running"));
+
+        let mut cache = CleanableCategory::for_type(CategoryType::DockerBuildCache);
+        cache.add_item(
+            CleanableItem::new("Docker Build Cache: k3x9".to_string(), 5000)
+                .with_kind(ItemKind::DockerBuildCache)
+                .with_details(ItemDetails::Docker { id: "k3x9".to_string() }),
+        );
+        let report = repo.clean_category(&cache, &CancellationToken::new()).unwrap();
+        assert!(report.removed().is_empty());
+        assert_eq!(report.skipped()[0].reason, "registro k3x9 em uso ou já removido");
+
+        assert_eq!(
+            daemon.requests(),
+            [
+                "DELETE /containers/3f2a1b4c5d6e7f80",
+                "DELETE /containers/9e8d7c6b5a49382a",
+                "POST /build/prune?all=true&filters=%7B%22id%22%3A%5B%22k3x9%22%5D%7D",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_vacuum_journal_with_configured_limits() {
+        let dir = tempfile::tempdir().unwrap();
+        fake_program(dir.path(), "journalctl", "echo \"$*\" > \"${0%/*}/args\"");
+
+        let mut category = CleanableCategory::for_type(CategoryType::SystemJournal);
+        category.add_item(CleanableItem::new("Journal do systemd".to_string(), 1024).with_kind(ItemKind::SystemJournal));
+
+        let repo = FileSystemCleanerRepository::new()
+            .with_journal(JournalConfig { vacuum_size_mb: 200, vacuum_time_days: 30 })
+            .with_command_runner(Arc::new(SystemCommandRunner::with_search_path(dir.path())));
+        let report = repo.clean_category(&category, &CancellationToken::new()).unwrap();
+
+        assert_eq!(report.removed(), ["Journal do systemd"]);
+        assert_eq!(
+            fs::read_to_string(dir.path().join("args")).unwrap(),
+            "--vacuum-size=200M --vacuum-time=30d\n"
+        );
+
+        fake_program(dir.path(), "journalctl", "echo 'Permission denied' >&2; exit 1");
+        let error = repo.clean_category(&category, &CancellationToken::new()).unwrap_err();
+        assert!(error.to_string().contains("Permission denied"));
+    }
+
+    #[test]
+    fn should_uninstall_each_listed_runtime_and_report_failures() {
+        let dir = tempfile::tempdir().unwrap();
+        fake_program(dir.path(), "flatpak", "echo \"$*\" >> \"${0%/*}/calls\"");
+        fake_program(dir.path(), "snap", "echo \"$*\" >> \"${0%/*}/calls\"; echo 'error: access denied' >&2; exit 1");
+
+        let mut category = CleanableCategory::for_type(CategoryType::UnusedRuntimes);
+        category.add_item(CleanableItem::new("org.gnome.Platform/x86_64/43".to_string(), 1024).with_kind(ItemKind::FlatpakRuntime));
+        category.add_item(
+            CleanableItem::new("/var/lib/snapd/snaps/core18_2790.snap".to_string(), 1024).with_kind(ItemKind::SnapRevision),
+        );
+
+        let repo = FileSystemCleanerRepository::new()
+            .with_command_runner(Arc::new(SystemCommandRunner::with_search_path(dir.path())));
+        let report = repo.clean_category(&category, &CancellationToken::new()).unwrap();
+
+        assert_eq!(report.removed(), ["org.gnome.Platform/x86_64/43"]);
+        assert_eq!(report.skipped()[0].path, "/var/lib/snapd/snaps/core18_2790.snap");
+        assert!(report.skipped()[0].reason.contains("access denied"));
+        assert_eq!(
+            fs::read_to_string(dir.path().join("calls")).unwrap(),
+            "uninstall --noninteractive org.gnome.Platform/x86_64/43\nremove core18 --revision=2790\n"
+        );
+    }
+
+    #[test]
+    fn should_truncate_active_logs_and_remove_rotated_ones() {
+        let dir = tempfile::tempdir().unwrap();
+        let (active, rotated) = (dir.path().join("app.log"), dir.path().join("app.log.1"));
+        fs::write(&active, b"linha em uso").unwrap();
+        fs::write(&rotated, b"linha antiga").unwrap();
+
+        let mut category = CleanableCategory::for_type(CategoryType::LogFiles);
+        category.add_item(CleanableItem::new(active.to_string_lossy().to_string(), 12).with_details(ItemDetails::Log(LogState::Active)));
+        category.add_item(CleanableItem::new(rotated.to_string_lossy().to_string(), 12).with_details(ItemDetails::Log(LogState::Rotated)));
+
+        let report = FileSystemCleanerRepository::new()
+            .clean_category(&category, &CancellationToken::new())
+            .unwrap();
+
+        assert_eq!(report.removed_count(), 2);
+        assert_eq!(fs::metadata(&active).unwrap().len(), 0);
+        assert!(!rotated.exists());
+    }
+
+    #[test]
+    fn should_skip_files_held_open_by_a_process() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path().canonicalize().unwrap();
+        let (held, cache, free) = (dir.join("held.tmp"), dir.join("cache"), dir.join("free.tmp"));
+        fs::create_dir_all(&cache).unwrap();
+        for path in [&held, &cache.join("db"), &free] {
+            fs::write(path, b"dados").unwrap();
+        }
+        let _held_file = fs::File::open(&held).unwrap();
+        let _held_cache = fs::File::open(cache.join("db")).unwrap();
+
+        let mut category = CleanableCategory::for_type(CategoryType::TemporaryFiles);
+        for path in [&held, &cache, &free] {
+            category.add_item(CleanableItem::new(path.to_string_lossy().to_string(), 5));
+        }
+
+        let report = FileSystemCleanerRepository::new()
+            .clean_category(&category, &CancellationToken::new())
+            .unwrap();
+
+        assert_eq!(report.removed(), [free.to_string_lossy().to_string()]);
+        assert_eq!(report.skipped().len(), 2);
+        assert!(report.skipped()[0].reason.ends_with(&format!("(PID {})", std::process::id())));
+        assert!(held.exists());
+        assert!(cache.join("db").exists());
+    }
+
+    #[test]
+    fn should_name_each_process_once_for_composite_items() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path().canonicalize().unwrap();
+        let (first, second) = (dir.join("cache2"), dir.join("GPUCache"));
+        for part in [&first, &second] {
+            fs::create_dir_all(part).unwrap();
+            fs::write(part.join("data"), b"dados").unwrap();
+        }
+
+        // Este processo segura as duas partes e um filho só a primeira,
+        // então os donos chegam fora de ordem: [este, filho, este]
+        let _first = fs::File::open(first.join("data")).unwrap();
+        let _second = fs::File::open(second.join("data")).unwrap();
+        let mut child = std::process::Command::new("sleep")
+            .arg("30")
+            .stdin(fs::File::open(first.join("data")).unwrap())
+            .spawn()
+            .unwrap();
+
+        let parts = [&first, &second].iter().map(|part| part.to_string_lossy().to_string()).collect();
+        let mut category = CleanableCategory::for_type(CategoryType::BrowserCaches);
+        category.add_item(
+            CleanableItem::new("Firefox · default".to_string(), 10).with_details(ItemDetails::BrowserProfile { parts }),
+        );
+
+        let report = FileSystemCleanerRepository::new()
+            .clean_category(&category, &CancellationToken::new())
+            .unwrap();
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        let reason = &report.skipped()[0].reason;
+        assert_eq!(reason.matches(&format!("(PID {})", std::process::id())).count(), 1);
+        assert_eq!(reason.matches(&format!("(PID {})", child.id())).count(), 1);
+    }
+
     #[test]
     fn should_validate_cleanable_category() {
         let repo = FileSystemCleanerRepository::new();
@@ -150,4 +720,253 @@ mod tests {
         category.add_item(CleanableItem::new("/tmp/test".to_string(), 100));
         assert!(repo.can_clean(&category)); // Non-empty category
     }
+
+    #[test]
+    fn should_never_remove_last_copy_of_duplicate_group() {
+        let dir = tempfile::tempdir().unwrap();
+        let original = dir.path().join("a.bin");
+        let copy = dir.path().join("b.bin");
+        fs::write(&original, b"same").unwrap();
+        fs::write(&copy, b"same").unwrap();
+
+        let original_path = original.to_string_lossy().to_string();
+        let copy_path = copy.to_string_lossy().to_string();
+
+        let mut category = CleanableCategory::for_type(CategoryType::DuplicateFiles);
+        category.add_item(CleanableItem::new(original_path.clone(), 4).with_details(ItemDetails::DuplicateOriginal));
+        category.add_item(CleanableItem::new(copy_path.clone(), 4).with_details(ItemDetails::DuplicateCopy { original: original_path.clone() }));
+        // Cópia inconsistente apontando para si mesma
+        category.add_item(CleanableItem::new(original_path.clone(), 4).with_details(ItemDetails::DuplicateCopy { original: original_path.clone() }));
+
+        let repo = FileSystemCleanerRepository::new();
+        let report = repo.clean_category(&category, &CancellationToken::new()).unwrap();
+
+        assert_eq!(report.removed(), [copy_path]);
+        assert!(original.exists());
+        assert!(!copy.exists());
+    }
+
+    #[test]
+    fn should_report_excluded_duplicate_copies_as_skipped() {
+        let dir = tempfile::tempdir().unwrap();
+        let original = dir.path().join("a.bin");
+        let copy = dir.path().join("fixtures/b.bin");
+        fs::create_dir_all(dir.path().join("fixtures")).unwrap();
+        fs::write(&original, b"same").unwrap();
+        fs::write(&copy, b"same").unwrap();
+
+        let mut category = CleanableCategory::for_type(CategoryType::DuplicateFiles);
+        category.add_item(
+            CleanableItem::new(copy.to_string_lossy().to_string(), 4)
+                .with_details(ItemDetails::DuplicateCopy { original: original.to_string_lossy().to_string() }),
+        );
+
+        let exclusions = ExclusionRules::new(&["fixtures/".to_string()], dir.path()).unwrap();
+        let report = FileSystemCleanerRepository::new()
+            .with_exclusions(exclusions)
+            .clean_category(&category, &CancellationToken::new())
+            .unwrap();
+
+        assert!(report.removed().is_empty());
+        assert_eq!(report.skipped()[0].path, copy.to_string_lossy());
+        assert_eq!(report.skipped()[0].reason, "excluído pelas regras");
+        assert!(copy.exists());
+    }
+
+    #[test]
+    fn should_keep_copy_when_original_changed() {
+        let dir = tempfile::tempdir().unwrap();
+        let original = dir.path().join("a.bin");
+        let copy = dir.path().join("b.bin");
+        fs::write(&original, b"edited").unwrap();
+        fs::write(&copy, b"before").unwrap();
+
+        let mut category = CleanableCategory::for_type(CategoryType::DuplicateFiles);
+        category.add_item(
+            CleanableItem::new(copy.to_string_lossy().to_string(), 6)
+                .with_details(ItemDetails::DuplicateCopy { original: original.to_string_lossy().to_string() }),
+        );
+
+        let repo = FileSystemCleanerRepository::new();
+        let report = repo.clean_category(&category, &CancellationToken::new()).unwrap();
+
+        assert_eq!(report.removed_count(), 0);
+        assert!(copy.exists());
+    }
+
+    #[test]
+    fn should_report_only_items_removed_before_cancellation() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut category = CleanableCategory::new("Logs".to_string());
+        for name in ["a.log", "b.log"] {
+            let path = dir.path().join(name);
+            fs::write(&path, b"log").unwrap();
+            category.add_item(CleanableItem::new(path.to_string_lossy().to_string(), 3));
+        }
+
+        let repo = FileSystemCleanerRepository::new();
+        let cancellation = CancellationToken::new();
+        cancellation.cancel();
+
+        let report = repo.clean_category(&category, &cancellation).unwrap();
+        assert!(report.is_cancelled());
+        assert_eq!(report.removed_count(), 0);
+        assert!(dir.path().join("a.log").exists());
+
+        let report = repo.clean_category(&category, &CancellationToken::new()).unwrap();
+        assert!(!report.is_cancelled());
+        assert_eq!(report.removed_count(), 2);
+    }
+
+    #[test]
+    fn should_stop_cleaning_a_directory_when_cancelled() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("target");
+        fs::create_dir_all(target.join("debug")).unwrap();
+        fs::write(target.join("debug/app"), b"binario").unwrap();
+
+        let cancellation = CancellationToken::new();
+        cancellation.cancel();
+        let result = FileSystemCleanerRepository::new().clean_directory(&target, &cancellation);
+
+        assert!(result.unwrap_err().to_string().contains("interrompida"));
+        assert!(target.join("debug/app").exists());
+    }
+
+    #[test]
+    fn should_skip_files_owned_by_another_user() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("alheio.log");
+        fs::write(&path, b"log").unwrap();
+
+        let mut category = CleanableCategory::new("Logs".to_string());
+        category.add_item(CleanableItem::new(path.to_string_lossy().to_string(), 3).with_owner(4242));
+
+        let report = FileSystemCleanerRepository::new()
+            .with_uid(1000)
+            .clean_category(&category, &CancellationToken::new())
+            .unwrap();
+        assert_eq!(report.removed_count(), 0);
+        assert_eq!(report.skipped()[0].path, path.to_string_lossy());
+        assert_eq!(report.skipped()[0].reason, "pertence a outro usuário");
+        assert!(path.exists());
+
+        let report = FileSystemCleanerRepository::new()
+            .with_uid(0)
+            .clean_category(&category, &CancellationToken::new())
+            .unwrap();
+        assert_eq!(report.removed_count(), 1);
+    }
+
+    #[test]
+    fn should_never_remove_excluded_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let fixtures = dir.path().join("fixtures.log");
+        let shielded = dir.path().join("build/keep");
+        fs::create_dir_all(&fixtures).unwrap();
+        fs::create_dir_all(&shielded).unwrap();
+        fs::write(fixtures.join("sample.log"), b"x").unwrap();
+        fs::write(shielded.join(".pccleanignore"), b"").unwrap();
+        fs::write(shielded.join("out.o"), b"x").unwrap();
+        fs::write(dir.path().join("build/tmp.o"), b"x").unwrap();
+
+        let mut category = CleanableCategory::new("Logs".to_string());
+        for path in [&fixtures, &fixtures.join("sample.log"), &dir.path().join("build")] {
+            category.add_item(CleanableItem::new(path.to_string_lossy().to_string(), 1));
+        }
+
+        let exclusions = ExclusionRules::new(&["*.log/".to_string()], dir.path()).unwrap();
+        let report = FileSystemCleanerRepository::new()
+            .with_exclusions(exclusions)
+            .clean_category(&category, &CancellationToken::new())
+            .unwrap();
+        assert_eq!(report.removed_count(), 0);
+        assert!(fixtures.join("sample.log").exists());
+        assert!(shielded.join("out.o").exists());
+        assert!(!dir.path().join("build/tmp.o").exists());
+
+        // `build` perdeu o `tmp.o`, mas ficou por causa de `build/keep`
+        let build = dir.path().join("build").to_string_lossy().to_string();
+        assert_eq!(report.partial().len(), 1);
+        assert_eq!(report.partial()[0].path, build);
+        assert!(report.partial()[0].freed_bytes > 0);
+        assert!(report
+            .skipped()
+            .iter()
+            .any(|skipped| skipped.path == shielded.to_string_lossy() && skipped.reason == "protegido pelas exclusões"));
+    }
+
+    #[test]
+    fn should_remove_symlinks_without_touching_their_targets() {
+        let dir = tempfile::tempdir().unwrap();
+        let outside = dir.path().join("nfs");
+        fs::create_dir_all(&outside).unwrap();
+        fs::write(outside.join("dados.log"), b"keep").unwrap();
+
+        let scanned = dir.path().join("scanned");
+        fs::create_dir_all(&scanned).unwrap();
+        std::os::unix::fs::symlink(&outside, scanned.join("share")).unwrap();
+        std::os::unix::fs::symlink(outside.join("dados.log"), dir.path().join("atalho.log")).unwrap();
+
+        let mut category = CleanableCategory::new("Temp".to_string());
+        for path in [&scanned.join("share"), &dir.path().join("atalho.log"), &scanned] {
+            category.add_item(CleanableItem::new(path.to_string_lossy().to_string(), 1));
+        }
+
+        let report = FileSystemCleanerRepository::new()
+            .clean_category(&category, &CancellationToken::new())
+            .unwrap();
+
+        assert_eq!(report.removed_count(), 3);
+        assert!(!scanned.exists());
+        assert!(fs::symlink_metadata(dir.path().join("atalho.log")).is_err());
+        assert!(outside.join("dados.log").exists());
+    }
+
+    #[test]
+    fn should_empty_trash_entries_with_their_info() {
+        let dir = tempfile::tempdir().unwrap();
+        let trash = dir.path().join("Trash");
+        fs::create_dir_all(trash.join("files/projeto")).unwrap();
+        fs::create_dir_all(trash.join("info")).unwrap();
+        fs::write(trash.join("files/projeto/main.rs"), b"fn main() {}").unwrap();
+        fs::write(trash.join("info/projeto.trashinfo"), "[Trash Info]\nPath=/home/dev/projeto\n").unwrap();
+        fs::write(trash.join("directorysizes"), "12 1700000000 projeto\n").unwrap();
+
+        let mut category = CleanableCategory::for_type(CategoryType::Trash);
+        let path = trash.join("files/projeto");
+        category.add_item(CleanableItem::new(path.to_string_lossy().to_string(), 12));
+
+        let report = FileSystemCleanerRepository::new()
+            .clean_category(&category, &CancellationToken::new())
+            .unwrap();
+
+        assert_eq!(report.removed_count(), 1);
+        assert!(!path.exists());
+        assert!(!trash.join("info/projeto.trashinfo").exists());
+        assert_eq!(fs::read_to_string(trash.join("directorysizes")).unwrap(), "");
+    }
+
+    #[test]
+    fn should_remove_every_part_of_a_composite_item() {
+        let dir = tempfile::tempdir().unwrap();
+        let profile = dir.path().join("Default");
+        fs::create_dir_all(profile.join("Cache")).unwrap();
+        fs::create_dir_all(profile.join("GPUCache")).unwrap();
+        fs::write(profile.join("Cache/data_0"), b"x").unwrap();
+        fs::write(profile.join("Cookies"), b"secret").unwrap();
+
+        let mut category = CleanableCategory::for_type(CategoryType::BrowserCaches);
+        let parts = ["Cache", "GPUCache"].iter().map(|name| profile.join(name).to_string_lossy().to_string());
+        category.add_item(CleanableItem::new("Chrome · Default".to_string(), 1).with_details(ItemDetails::BrowserProfile { parts: parts.collect() }));
+
+        let report = FileSystemCleanerRepository::new()
+            .clean_category(&category, &CancellationToken::new())
+            .unwrap();
+
+        assert_eq!(report.removed(), ["Chrome · Default".to_string()]);
+        assert!(!profile.join("Cache").exists());
+        assert!(!profile.join("GPUCache").exists());
+        assert!(profile.join("Cookies").exists());
+    }
 }
@@ -1,47 +1,116 @@
-use crate::domain::repositories::ScannerRepository;
+use crate::domain::repositories::{ScannerRepository, ScanProgressObserver, NoScanProgress};
 use crate::domain::entities::{ScanResult, CleanableCategory, CleanableItem};
-use crate::domain::value_objects::CategoryType;
-use anyhow::Result;
-use walkdir::WalkDir;
-use std::path::Path;
-use std::process::Command;
+use crate::domain::value_objects::{CategoryType, Ecosystem, ItemDetails, ItemKind, LogState, ScanProgress, CancellationToken};
+use crate::infrastructure::config::{ScanConfig, AgeBasis, ExclusionRules};
+use crate::infrastructure::command::{CommandRunner, default_command_runner};
+use crate::infrastructure::docker::{DockerClient, short_id};
+use crate::infrastructure::platform::{PlatformPaths, default_platform_paths};
+use crate::shared::format_bytes;
+use super::scan_context::{DiskUsage, ScanContext, ScanTotals, item_from_metadata};
+use super::duplicate_file_finder::DuplicateFileFinder;
+use super::trash::trash_entries;
+use super::browser_cache_finder::browser_profiles;
+use super::build_artifacts::{artifact_rule, last_project_activity};
+use super::cargo_artifacts::{CARGO_HOME_DIRS, is_cargo_target, last_build_time};
+use super::log_rotation::classify_log;
+use super::open_files::OpenFiles;
+use super::crash_dumps::{COREDUMPCTL, COREDUMPCTL_LIST_ARGS, crash_info, is_core_file, parse_coredump_list};
+use super::app_runtimes::{FLATPAK, FLATPAK_APP_RUNTIMES_ARGS, FLATPAK_RUNTIMES_ARGS, SNAP, SNAP_LIST_ARGS, parse_removable_snap_revisions, unused_flatpak_runtimes};
+use super::docker_items::{BUILD_CACHE_LABEL, CONTAINER_LABEL, IMAGE_LABEL, VOLUME_LABEL};
+use super::journal::{JOURNALCTL, parse_disk_usage, reclaimable_estimate, vacuum_args};
+use anyhow::{Result, anyhow};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Número máximo de categorias escaneadas ao mesmo tempo.
+const MAX_SCAN_WORKERS: usize = 4;
 
 /// Implementação concreta do ScannerRepository.
 /// Realiza varreduras no sistema de arquivos e serviços.
-pub struct FileSystemScannerRepository;
+pub struct FileSystemScannerRepository {
+    config: ScanConfig,
+    platform: Arc<dyn PlatformPaths>,
+    commands: Arc<dyn CommandRunner>,
+    docker: DockerClient,
+    exclusions: ExclusionRules,
+}
 
 impl FileSystemScannerRepository {
     pub fn new() -> Self {
-        Self
+        Self::with_config(ScanConfig::default())
+    }
+
+    /// Cria o scanner a partir da configuração do usuário.
+    pub fn with_config(config: ScanConfig) -> Self {
+        Self {
+            config,
+            platform: default_platform_paths(),
+            commands: default_command_runner(),
+            docker: DockerClient::from_env(),
+            exclusions: ExclusionRules::default(),
+        }
+    }
+
+    /// Define os caminhos protegidos da varredura.
+    pub fn with_exclusions(mut self, exclusions: ExclusionRules) -> Self {
+        self.exclusions = exclusions;
+        self
+    }
+
+    /// Substitui o provedor de caminhos da plataforma.
+    #[allow(dead_code)]
+    pub fn with_platform_paths(mut self, platform: Arc<dyn PlatformPaths>) -> Self {
+        self.platform = platform;
+        self
+    }
+
+    /// Substitui o executor das ferramentas externas.
+    #[allow(dead_code)]
+    pub fn with_command_runner(mut self, commands: Arc<dyn CommandRunner>) -> Self {
+        self.commands = commands;
+        self
+    }
+
+    /// Substitui o cliente da API do Docker.
+    #[allow(dead_code)]
+    pub fn with_docker(mut self, docker: DockerClient) -> Self {
+        self.docker = docker;
+        self
     }
 
-    /// Escaneia arquivos de log do sistema.
-    fn scan_log_files(&self) -> Result<Vec<CleanableItem>> {
+    /// Escaneia arquivos de log do sistema, classificados em rotacionados,
+    /// órfãos e ativos. Logs ativos só entram se a configuração permitir truncá-los.
+    fn scan_log_files(&self, ctx: &ScanContext) -> Result<Vec<CleanableItem>> {
+        let orphan_after = Duration::from_secs(self.config.logs.orphan_after_days * 24 * 60 * 60);
+        let open_files = OpenFiles::snapshot();
+        let now = SystemTime::now();
         let mut items = Vec::new();
-        let home = std::env::var("HOME").unwrap_or_default();
-        let user_logs = format!("{}/Library/Logs", home);
-        
-        let log_dirs = vec!["/var/log", "/tmp", user_logs.as_str()];
 
-        for dir in log_dirs {
-            if !Path::new(dir).exists() {
+        for dir in self.platform.log_dirs() {
+            if !dir.exists() {
                 continue;
             }
 
-            for entry in WalkDir::new(dir)
-                .max_depth(3)
-                .into_iter()
-                .filter_map(|e| e.ok())
-                .filter(|e| e.path().is_file())
+            for entry in ctx
+                .files(&dir, self.config.depth.log_files)
                 .filter(|e| Self::is_log_file(e.path()))
             {
                 if let Ok(metadata) = entry.metadata() {
-                    let size = metadata.len();
-                    if size > 0 {
-                        items.push(CleanableItem::new(
-                            entry.path().to_string_lossy().to_string(),
-                            size,
-                        ));
+                    let state = classify_log(entry.path(), &metadata, open_files.as_ref(), now, orphan_after);
+                    if state == LogState::Active && !self.config.logs.truncate_active {
+                        continue;
+                    }
+
+                    let usage = ctx.usage(&metadata);
+                    if usage.apparent > 0 {
+                        ctx.item_found(usage.on_disk);
+                        items.push(item_from_metadata(entry.path(), &metadata, usage).with_details(ItemDetails::Log(state)));
                     }
                 }
             }
@@ -50,42 +119,75 @@ impl FileSystemScannerRepository {
         Ok(items)
     }
 
+    /// Raízes de outras categorias que ficam dentro das de `category_type`.
+    /// `~/.cache` contém os caches dos navegadores, por exemplo: sem isso os
+    /// mesmos blocos entrariam em duas categorias e inflariam o total.
+    fn roots_claimed_elsewhere(&self, category_type: CategoryType) -> Vec<PathBuf> {
+        match category_type {
+            CategoryType::TemporaryFiles => {
+                let mut roots = self.platform.package_cache_dirs();
+                roots.extend(self.platform.trash_dirs());
+                roots.extend(self.platform.browser_dirs().into_iter().map(|dirs| dirs.cache_dir));
+                roots.push(self.platform.cargo_home());
+                roots
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Verifica se um arquivo já é listado pela categoria de logs,
+    /// dados os diretórios de logs já resolvidos.
+    fn is_claimed_log(&self, path: &Path, log_dirs: &[PathBuf]) -> bool {
+        Self::is_log_file(path)
+            && log_dirs.iter().any(|dir| {
+                path.strip_prefix(dir)
+                    .is_ok_and(|relative| relative.components().count() <= self.config.depth.log_files)
+            })
+    }
+
     fn is_log_file(path: &Path) -> bool {
+        // Só o nome do arquivo conta: diretórios como `fixtures.log/` não tornam logs o que contêm
         path.extension()
-            .map_or(false, |ext| ext == "log" || ext == "LOG")
-            || path.to_string_lossy().contains(".log")
+            .is_some_and(|ext| ext == "log" || ext == "LOG")
+            || path.file_name().is_some_and(|name| name.to_string_lossy().contains(".log"))
     }
 
     /// Escaneia arquivos temporários.
-    fn scan_temp_files(&self) -> Result<Vec<CleanableItem>> {
+    fn scan_temp_files(&self, ctx: &ScanContext) -> Result<Vec<CleanableItem>> {
         let mut items = Vec::new();
-        let home = std::env::var("HOME").unwrap_or_default();
-        let user_caches = format!("{}/Library/Caches", home);
-        
-        let temp_dirs = vec!["/tmp", "/var/tmp", user_caches.as_str()];
+        let log_dirs: Vec<PathBuf> = self.platform.log_dirs()
+            .into_iter()
+            .map(|dir| fs::canonicalize(&dir).unwrap_or(dir))
+            .collect();
 
-        for dir in temp_dirs {
-            if !Path::new(dir).exists() {
+        for dir in self.platform.temp_dirs() {
+            if !dir.exists() {
                 continue;
             }
 
-            let entries: Vec<_> = WalkDir::new(dir)
-                .max_depth(2)
-                .into_iter()
-                .filter_map(|e| e.ok())
-                .filter(|e| e.path().is_file())
-                .take(100) // Limitar para performance
-                .collect();
+            let limit = self.config.limits.temp_files_per_dir.unwrap_or(usize::MAX);
+            let mut listed = 0;
 
-            for entry in entries {
+            // Logs em `/tmp` ficam só na categoria de logs
+            for entry in ctx
+                .files(&dir, self.config.depth.temp_files)
+                .filter(|e| !self.is_claimed_log(e.path(), &log_dirs))
+            {
                 if let Ok(metadata) = entry.metadata() {
-                    let size = metadata.len();
-                    if size > 0 {
-                        items.push(CleanableItem::new(
-                            entry.path().to_string_lossy().to_string(),
-                            size,
-                        ));
+                    let usage = ctx.usage(&metadata);
+                    if usage.apparent == 0 {
+                        continue;
                     }
+
+                    // Acima do limite só contabilizamos, sem guardar o item
+                    if listed >= limit {
+                        ctx.skip(1, usage.on_disk);
+                        continue;
+                    }
+
+                    listed += 1;
+                    ctx.item_found(usage.on_disk);
+                    items.push(item_from_metadata(entry.path(), &metadata, usage));
                 }
             }
         }
@@ -94,31 +196,18 @@ impl FileSystemScannerRepository {
     }
 
     /// Escaneia imagens Docker não utilizadas.
-    fn scan_docker_images(&self) -> Result<Vec<CleanableItem>> {
+    /// Uma única chamada à API lista as imagens com o tamanho de cada uma.
+    fn scan_docker_images(&self, ctx: &ScanContext) -> Result<Vec<CleanableItem>> {
         let mut items = Vec::new();
 
-        let output = Command::new("docker")
-            .args(&["images", "--filter", "dangling=true", "-q", "--no-trunc"])
-            .output();
-
-        if let Ok(output) = output {
-            if output.status.success() {
-                let image_ids = String::from_utf8_lossy(&output.stdout);
-                
-                for image_id in image_ids.lines() {
-                    let trimmed_id = image_id.trim();
-                    if trimmed_id.is_empty() {
-                        continue;
-                    }
-
-                    let size = Self::get_docker_image_size(trimmed_id).unwrap_or(0);
-                    if size > 0 {
-                        items.push(CleanableItem::new(
-                            format!("Docker Image: {}", &trimmed_id[..12.min(trimmed_id.len())]),
-                            size,
-                        ));
-                    }
-                }
+        for image in self.docker.dangling_images()?.into_iter().take_while(|_| ctx.is_active()) {
+            if image.size > 0 {
+                ctx.item_found(image.size);
+                items.push(
+                    CleanableItem::new(format!("{}{}", IMAGE_LABEL, short_id(&image.id)), image.size)
+                        .with_kind(ItemKind::DockerImage)
+                        .with_details(ItemDetails::Docker { id: image.id.clone() }),
+                );
             }
         }
 
@@ -133,42 +222,26 @@ impl FileSystemScannerRepository {
         Ok(items)
     }
 
-    fn get_docker_image_size(image_id: &str) -> Option<u64> {
-        Command::new("docker")
-            .args(&["inspect", "-f", "{{.Size}}", image_id])
-            .output()
-            .ok()
-            .and_then(|output| String::from_utf8(output.stdout).ok())
-            .and_then(|size_str| size_str.trim().parse::<u64>().ok())
-    }
-
-    /// Escaneia volumes Docker não utilizados.
-    fn scan_docker_volumes(&self) -> Result<Vec<CleanableItem>> {
+    /// Escaneia volumes Docker não utilizados, com o uso medido pelo `/system/df`.
+    /// Volumes que o Docker não mede (ex.: drivers remotos) ficam sem tamanho.
+    fn scan_docker_volumes(&self, ctx: &ScanContext) -> Result<Vec<CleanableItem>> {
         let mut items = Vec::new();
+        let volumes = self.docker.dangling_volumes()?;
+        let sizes = if volumes.is_empty() { Default::default() } else { self.docker.volume_sizes().unwrap_or_default() };
 
-        let output = Command::new("docker")
-            .args(&["volume", "ls", "-qf", "dangling=true"])
-            .output();
-
-        if let Ok(output) = output {
-            if output.status.success() {
-                let volume_names = String::from_utf8_lossy(&output.stdout);
-                
-                for volume_name in volume_names.lines() {
-                    let trimmed_name = volume_name.trim();
-                    if trimmed_name.is_empty() {
-                        continue;
-                    }
+        for volume in volumes.into_iter().take_while(|_| ctx.is_active()) {
+            let size = sizes.get(&volume.name).copied().flatten();
+            let item = CleanableItem::new(format!("{}{}", VOLUME_LABEL, volume.name), size.unwrap_or(0))
+                .with_kind(ItemKind::DockerVolume)
+                .with_details(ItemDetails::Docker { id: volume.name.clone() });
 
-                    // Docker não fornece tamanho direto para volumes, estimamos
-                    let estimated_size = 100_000_000; // 100MB
-                    
-                    items.push(CleanableItem::new(
-                        format!("Docker Volume: {}", trimmed_name),
-                        estimated_size,
-                    ));
+            items.push(match size {
+                Some(size) => {
+                    ctx.item_found(size);
+                    item
                 }
-            }
+                None => item.with_unknown_size(),
+            });
         }
 
         if items.is_empty() {
@@ -181,59 +254,163 @@ impl FileSystemScannerRepository {
         Ok(items)
     }
 
-    /// Escaneia pacotes de desenvolvimento (node_modules, cargo cache, etc).
-    fn scan_dev_packages(&self) -> Result<Vec<CleanableItem>> {
+    /// Escaneia o cache de build do BuildKit, com a data de criação e do último uso.
+    /// Registros em uso por um build em andamento ficam de fora.
+    fn scan_docker_build_cache(&self, ctx: &ScanContext) -> Result<Vec<CleanableItem>> {
+        let mut items = Vec::new();
+
+        for record in self.docker.build_cache()?.into_iter().take_while(|_| ctx.is_active()) {
+            if record.size > 0 {
+                ctx.item_found(record.size);
+                items.push(
+                    CleanableItem::new(format!("{}{}", BUILD_CACHE_LABEL, record.id), record.size)
+                        .with_kind(ItemKind::DockerBuildCache)
+                        .with_times(record.created_at(), record.last_used_at())
+                        .with_details(ItemDetails::Docker { id: record.id.clone() }),
+                );
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Escaneia contêineres parados, medidos pela camada gravável.
+    /// Sem o `SizeRw` na resposta, o contêiner fica sem tamanho.
+    fn scan_docker_containers(&self, ctx: &ScanContext) -> Result<Vec<CleanableItem>> {
+        let mut items = Vec::new();
+
+        for container in self.docker.stopped_containers()?.into_iter().take_while(|_| ctx.is_active()) {
+            let label = match container.name() {
+                Some(name) => format!("{}{} ({})", CONTAINER_LABEL, short_id(&container.id), name),
+                None => format!("{}{}", CONTAINER_LABEL, short_id(&container.id)),
+            };
+            let item = CleanableItem::new(label, container.size_rw.unwrap_or(0))
+                .with_kind(ItemKind::DockerContainer)
+                .with_times(container.created_at(), None)
+                .with_details(ItemDetails::Docker { id: container.id.clone() });
+
+            match container.size_rw {
+                // Camada gravável vazia: nada a liberar
+                Some(0) => {}
+                Some(size) => {
+                    ctx.item_found(size);
+                    items.push(item);
+                }
+                None => items.push(item.with_unknown_size()),
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Escaneia pacotes de desenvolvimento: artefatos de build reconhecidos
+    /// pela tabela de regras por ecossistema e caches de pacotes.
+    fn scan_dev_packages(&self, ctx: &ScanContext) -> Result<Vec<CleanableItem>> {
+        let min_idle = Duration::from_secs(self.config.dev_packages.stale_project_days * 24 * 60 * 60);
+        let now = SystemTime::now();
+        // Um projeto com vários artefatos é medido uma única vez
+        let mut activity_by_project = HashMap::new();
         let mut items = Vec::new();
-        let home = std::env::var("HOME").unwrap_or_default();
         
-        // Procurar node_modules
-        let common_dev_dirs = vec![
-            format!("{}/Projects", home),
-            format!("{}/Documents", home),
-            format!("{}/Desktop", home),
-        ];
-
-        for base_dir in common_dev_dirs {
-            if !Path::new(&base_dir).exists() {
+        // Procurar artefatos de projetos nas raízes configuradas
+        for base_dir in self.config.resolved_roots(self.platform.home()) {
+            if !base_dir.exists() {
                 continue;
             }
 
-            let entries: Vec<_> = WalkDir::new(&base_dir)
-                .max_depth(4)
-                .into_iter()
-                .filter_map(|e| e.ok())
-                .filter(|e| {
-                    e.path().is_dir() && 
-                    e.path().file_name().map_or(false, |n| n == "node_modules")
-                })
-                .take(20)
-                .collect();
-
-            for entry in entries {
-                if let Ok(size) = Self::calculate_directory_size(entry.path()) {
-                    if size > 0 {
-                        items.push(CleanableItem::new(
-                            entry.path().to_string_lossy().to_string(),
-                            size,
-                        ));
-                    }
+            let limit = self.config.limits.dev_packages_per_root.unwrap_or(usize::MAX);
+            let mut listed_bytes = 0u64;
+            let mut listed = 0usize;
+            let mut skipped = 0usize;
+            let mut walker = ctx.walk(&base_dir, self.config.depth.dev_packages);
+
+            while let Some(entry) = walker.next() {
+                if !ctx.is_active() {
+                    break;
+                }
+
+                let Ok(entry) = entry else {
+                    continue;
+                };
+                ctx.visit(&entry);
+
+                if !entry.file_type().is_dir() {
+                    continue;
+                }
+                let Some(rule) = artifact_rule(entry.path()) else {
+                    continue;
+                };
+
+                // Artefatos aninhados já estão contidos no tamanho do diretório pai
+                walker.skip_current_dir();
+
+                // Projetos com alterações recentes não têm artefatos candidatos.
+                // Sem tempo mínimo de inatividade as fontes nem são percorridas
+                let active_at = if min_idle.is_zero() {
+                    None
+                } else {
+                    let project = entry.path().parent().unwrap_or(entry.path()).to_path_buf();
+                    *activity_by_project
+                        .entry(project)
+                        .or_insert_with_key(|project| last_project_activity(project, self.config.depth.project_sources))
+                };
+                let stale = active_at.is_none_or(|time| now.duration_since(time).is_ok_and(|idle| idle >= min_idle));
+                if !stale {
+                    continue;
+                }
+
+                if listed >= limit {
+                    skipped += 1;
+                    continue;
+                }
+
+                let (Ok(metadata), Ok(usage)) = (entry.metadata(), self.calculate_directory_size(entry.path(), ctx)) else {
+                    continue;
+                };
+
+                if usage.apparent > 0 {
+                    listed += 1;
+                    listed_bytes += usage.on_disk;
+                    ctx.item_found(usage.on_disk);
+                    items.push(
+                        item_from_metadata(entry.path(), &metadata, usage).with_details(ItemDetails::BuildArtifact {
+                            project: project_of(entry.path()),
+                            ecosystem: rule.ecosystem,
+                            active_at,
+                        }),
+                    );
                 }
             }
-        }
 
-        // Cache do NPM
-        let npm_cache = format!("{}/.npm", home);
-        if let Ok(size) = Self::calculate_directory_size(Path::new(&npm_cache)) {
-            if size > 0 {
-                items.push(CleanableItem::new(npm_cache, size));
+            // Estimar os diretórios ignorados pela média dos listados
+            if skipped > 0 {
+                let average = listed_bytes / listed.max(1) as u64;
+                ctx.skip(skipped, average * skipped as u64);
             }
         }
 
-        // Cache do Cargo
-        let cargo_cache = format!("{}/.cargo/registry", home);
-        if let Ok(size) = Self::calculate_directory_size(Path::new(&cargo_cache)) {
-            if size > 0 {
-                items.push(CleanableItem::new(cargo_cache, size));
+        // Caches de gerenciadores de pacotes (NPM, Cargo, etc)
+        for cache_dir in self.platform.package_cache_dirs() {
+            if ctx.is_excluded(&cache_dir) {
+                continue;
+            }
+
+            // Um cache que é link para outro lugar não é seguido
+            let Ok(metadata) = fs::symlink_metadata(&cache_dir) else {
+                continue;
+            };
+            if !metadata.is_dir() {
+                continue;
+            }
+
+            ctx.enter_directory(&cache_dir);
+            let Ok(usage) = self.calculate_directory_size(&cache_dir, ctx) else {
+                continue;
+            };
+
+            if usage.apparent > 0 {
+                ctx.item_found(usage.on_disk);
+                items.push(item_from_metadata(&cache_dir, &metadata, usage));
             }
         }
 
@@ -247,39 +424,485 @@ impl FileSystemScannerRepository {
         Ok(items)
     }
 
-    fn calculate_directory_size(path: &Path) -> Result<u64> {
-        let mut size = 0u64;
+    /// Escaneia a categoria do contexto.
+    fn scan_category_with(&self, ctx: &ScanContext) -> Result<Vec<CleanableItem>> {
+        match ctx.category {
+            CategoryType::LogFiles => self.scan_log_files(ctx),
+            CategoryType::TemporaryFiles => self.scan_temp_files(ctx),
+            CategoryType::DockerImages => self.scan_docker_images(ctx),
+            CategoryType::DockerVolumes => self.scan_docker_volumes(ctx),
+            CategoryType::DevelopmentPackages => self.scan_dev_packages(ctx),
+            CategoryType::DuplicateFiles => self.scan_duplicate_files(ctx),
+            CategoryType::LargeFiles => self.scan_large_files(ctx),
+            CategoryType::Trash => self.scan_trash(ctx),
+            CategoryType::BrowserCaches => self.scan_browser_caches(ctx),
+            CategoryType::RustArtifacts => self.scan_rust_artifacts(ctx),
+            CategoryType::SystemJournal => self.scan_system_journal(ctx),
+            CategoryType::CrashDumps => self.scan_crash_dumps(ctx),
+            CategoryType::UnusedRuntimes => self.scan_unused_runtimes(ctx),
+            CategoryType::DockerBuildCache => self.scan_docker_build_cache(ctx),
+            CategoryType::DockerContainers => self.scan_docker_containers(ctx),
+        }
+    }
+
+    /// Escaneia arquivos duplicados nas raízes configuradas.
+    fn scan_duplicate_files(&self, ctx: &ScanContext) -> Result<Vec<CleanableItem>> {
+        let roots = self.config.resolved_roots(self.platform.home());
+        let finder = DuplicateFileFinder::new(
+            &roots,
+            self.config.depth.duplicate_files,
+            self.config.duplicates.min_size_bytes,
+        );
+
+        Ok(finder.find(ctx))
+    }
+
+    /// Escaneia arquivos grandes e sem uso recente no home do usuário.
+    /// O resultado é ordenado do maior para o menor.
+    fn scan_large_files(&self, ctx: &ScanContext) -> Result<Vec<CleanableItem>> {
+        let settings = &self.config.large_files;
+        let min_age = Duration::from_secs(settings.min_age_days * 24 * 60 * 60);
+        let now = SystemTime::now();
+        let home = self.platform.home();
+        let mut items = Vec::new();
+
+        if home.as_os_str().is_empty() || !home.exists() {
+            return Ok(items);
+        }
+
+        for entry in ctx
+            .files(home, self.config.depth.large_files)
+            .filter(|e| e.file_type().is_file())
+        {
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+
+            if metadata.len() < settings.min_size_bytes {
+                continue;
+            }
+
+            let item = item_from_metadata(entry.path(), &metadata, ctx.usage(&metadata));
+            let age = match settings.age_basis {
+                AgeBasis::Accessed => item.accessed_age(now),
+                AgeBasis::Modified => item.modified_age(now),
+            };
+
+            if age.is_some_and(|age| age >= min_age) {
+                ctx.item_found(item.size_in_bytes());
+                items.push(item);
+            }
+        }
+
+        items.sort_by_key(|item| std::cmp::Reverse((item.size_in_bytes(), item.apparent_size())));
+        Ok(items)
+    }
+
+    /// Escaneia as lixeiras freedesktop do usuário (home e pontos de montagem).
+    /// Com `min_age_days`, só lista o que está na lixeira há tempo suficiente.
+    fn scan_trash(&self, ctx: &ScanContext) -> Result<Vec<CleanableItem>> {
+        let min_age = Duration::from_secs(self.config.trash.min_age_days * 24 * 60 * 60);
+        let now = SystemTime::now();
+        let mut items = Vec::new();
+
+        for trash_dir in self.platform.trash_dirs() {
+            if !ctx.is_active() {
+                break;
+            }
+            ctx.enter_directory(&trash_dir);
+
+            for entry in trash_entries(&trash_dir) {
+                if ctx.is_excluded(&entry.path) {
+                    continue;
+                }
+
+                let Ok(metadata) = fs::symlink_metadata(&entry.path) else {
+                    continue;
+                };
+                let usage = if metadata.is_dir() {
+                    self.calculate_directory_size(&entry.path, ctx)?
+                } else {
+                    ctx.usage(&metadata)
+                };
+
+                let item = item_from_metadata(&entry.path, &metadata, usage).with_details(ItemDetails::Trashed {
+                    from: entry.original_path.to_string_lossy().to_string(),
+                    at: entry.deleted_at,
+                });
+
+                // Sem data de exclusão o item só entra quando não há idade mínima
+                let old_enough = min_age.is_zero() || item.trashed_age(now).is_some_and(|age| age >= min_age);
+                if old_enough {
+                    ctx.item_found(item.size_in_bytes());
+                    items.push(item);
+                }
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Escaneia os caches dos perfis do Firefox e dos navegadores Chromium.
+    /// Cada perfil vira um item cujas partes são os seus diretórios de cache.
+    fn scan_browser_caches(&self, ctx: &ScanContext) -> Result<Vec<CleanableItem>> {
+        let mut items = Vec::new();
+
+        for dirs in self.platform.browser_dirs() {
+            for profile in browser_profiles(&dirs) {
+                if !ctx.is_active() {
+                    return Ok(items);
+                }
+
+                // Cada parte é checada sem seguir links: só diretórios reais são medidos
+                let cache_dirs: Vec<(&Path, fs::Metadata)> = profile
+                    .cache_dirs
+                    .iter()
+                    .map(|dir| dir.as_path())
+                    .filter(|dir| !ctx.is_excluded(dir))
+                    .filter_map(|dir| fs::symlink_metadata(dir).ok().map(|metadata| (dir, metadata)))
+                    .filter(|(_, metadata)| metadata.is_dir())
+                    .collect();
+                let Some((_, metadata)) = cache_dirs.first() else {
+                    continue;
+                };
+
+                let mut usage = DiskUsage::default();
+                for (dir, _) in &cache_dirs {
+                    ctx.enter_directory(dir);
+                    usage.add(self.calculate_directory_size(dir, ctx)?);
+                }
+
+                if usage.on_disk > 0 {
+                    ctx.item_found(usage.on_disk);
+                    let parts = cache_dirs.iter().map(|(dir, _)| dir.to_string_lossy().to_string()).collect();
+                    items.push(
+                        item_from_metadata(Path::new(&profile.label), metadata, usage)
+                            .with_details(ItemDetails::BrowserProfile { parts }),
+                    );
+                }
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Escaneia diretórios `target/` de projetos Rust e as partes do `$CARGO_HOME`.
+    /// A data de modificação de cada `target/` é a do último build.
+    fn scan_rust_artifacts(&self, ctx: &ScanContext) -> Result<Vec<CleanableItem>> {
+        let min_age = Duration::from_secs(self.config.rust.target_min_age_days * 24 * 60 * 60);
+        let now = SystemTime::now();
+        let mut items = Vec::new();
+
+        for base_dir in self.config.resolved_roots(self.platform.home()) {
+            if !base_dir.exists() {
+                continue;
+            }
+
+            let mut walker = ctx.walk(&base_dir, self.config.depth.rust_targets);
+            while let Some(entry) = walker.next() {
+                if !ctx.is_active() {
+                    break;
+                }
+
+                let Ok(entry) = entry else {
+                    continue;
+                };
+                ctx.visit(&entry);
+
+                if !entry.file_type().is_dir() {
+                    continue;
+                }
+                if entry.file_name() == "node_modules" || entry.file_name() == ".git" {
+                    walker.skip_current_dir();
+                    continue;
+                }
+                if !is_cargo_target(entry.path()) {
+                    continue;
+                }
+
+                walker.skip_current_dir();
+                let (Ok(metadata), Ok(usage)) = (entry.metadata(), self.calculate_directory_size(entry.path(), ctx)) else {
+                    continue;
+                };
+
+                let item = item_from_metadata(entry.path(), &metadata, usage)
+                    .with_times(last_build_time(entry.path()), metadata.accessed().ok())
+                    .with_details(ItemDetails::BuildArtifact {
+                        project: project_of(entry.path()),
+                        ecosystem: Ecosystem::Rust,
+                        active_at: None,
+                    });
+                let untouched = min_age.is_zero() || item.modified_age(now).is_some_and(|age| age >= min_age);
+
+                if usage.apparent > 0 && untouched {
+                    ctx.item_found(usage.on_disk);
+                    items.push(item);
+                }
+            }
+        }
+
+        let cargo_home = self.platform.cargo_home();
+        for relative in CARGO_HOME_DIRS {
+            let dir = cargo_home.join(relative);
+            if ctx.is_excluded(&dir) {
+                continue;
+            }
+            let Ok(metadata) = fs::symlink_metadata(&dir) else {
+                continue;
+            };
+            if !metadata.is_dir() {
+                continue;
+            }
+
+            ctx.enter_directory(&dir);
+            let usage = self.calculate_directory_size(&dir, ctx)?;
+            if usage.apparent > 0 {
+                ctx.item_found(usage.on_disk);
+                items.push(item_from_metadata(&dir, &metadata, usage));
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Mede o journal do systemd com `journalctl --disk-usage`.
+    /// Sem `journalctl` ou sem limites de limpeza configurados, nada é listado.
+    fn scan_system_journal(&self, ctx: &ScanContext) -> Result<Vec<CleanableItem>> {
+        if vacuum_args(&self.config.journal).is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let Ok(output) = self.commands.run(JOURNALCTL, &["--disk-usage"]) else {
+            return Ok(Vec::new());
+        };
+        let Some(usage) = parse_disk_usage(&output.stdout).filter(|_| output.success) else {
+            return Ok(Vec::new());
+        };
+
+        let reclaimable = reclaimable_estimate(usage, &self.config.journal);
+        ctx.item_found(reclaimable);
+
+        Ok(vec![CleanableItem::new(
+            format!("Journal do systemd ({} em uso)", format_bytes(usage)),
+            reclaimable,
+        )
+        .with_kind(ItemKind::SystemJournal)])
+    }
+
+    /// Escaneia core dumps e relatórios de falha do sistema e arquivos `core`
+    /// soltos nas raízes. Os dumps do systemd usam os metadados do `coredumpctl`.
+    fn scan_crash_dumps(&self, ctx: &ScanContext) -> Result<Vec<CleanableItem>> {
+        let min_age = Duration::from_secs(self.config.crash_dumps.min_age_days * 24 * 60 * 60);
+        let now = SystemTime::now();
+        let records = self
+            .commands
+            .run(COREDUMPCTL, COREDUMPCTL_LIST_ARGS)
+            .ok()
+            .filter(|output| output.success)
+            .map(|output| parse_coredump_list(&output.stdout))
+            .unwrap_or_default();
+        let mut items = Vec::new();
+
+        let mut push_dump = |path: &Path, metadata: &fs::Metadata| {
+            let usage = ctx.usage(metadata);
+            let mut item = item_from_metadata(path, metadata, usage);
+            let crashed_at = match crash_info(path, &records) {
+                Some(info) => {
+                    item = item.with_details(ItemDetails::Crash { exe: info.exe, at: info.crashed_at });
+                    info.crashed_at
+                }
+                None => metadata.modified().ok(),
+            };
+
+            let old_enough = min_age.is_zero()
+                || crashed_at.is_some_and(|time| now.duration_since(time).is_ok_and(|age| age >= min_age));
+            if usage.apparent > 0 && old_enough {
+                ctx.item_found(usage.on_disk);
+                items.push(item);
+            }
+        };
+
+        for dir in self.platform.crash_dump_dirs() {
+            if !dir.exists() {
+                continue;
+            }
+
+            for entry in ctx.files(&dir, 2).filter(|entry| entry.file_type().is_file()) {
+                if let Ok(metadata) = entry.metadata() {
+                    push_dump(entry.path(), &metadata);
+                }
+            }
+        }
+
+        for base_dir in self.config.resolved_roots(self.platform.home()) {
+            if !base_dir.exists() {
+                continue;
+            }
+
+            let mut walker = ctx.walk(&base_dir, self.config.depth.core_files);
+            while let Some(entry) = walker.next() {
+                if !ctx.is_active() {
+                    break;
+                }
+
+                let Ok(entry) = entry else {
+                    continue;
+                };
+                ctx.visit(&entry);
+
+                if entry.file_type().is_dir() {
+                    if entry.file_name() == "node_modules" || entry.file_name() == ".git" {
+                        walker.skip_current_dir();
+                    }
+                    continue;
+                }
+
+                if entry.file_type().is_file() && is_core_file(entry.path()) {
+                    if let Ok(metadata) = entry.metadata() {
+                        push_dump(entry.path(), &metadata);
+                    }
+                }
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Lista runtimes Flatpak sem uso e revisões Snap desativadas.
+    /// O Flatpak informa o tamanho de cada runtime; o snap não, então vale o
+    /// tamanho do arquivo `.snap` da revisão.
+    fn scan_unused_runtimes(&self, ctx: &ScanContext) -> Result<Vec<CleanableItem>> {
+        let mut items = Vec::new();
+
+        // Sem a lista de aplicativos não dá para saber o que está em uso
+        let flatpak = [FLATPAK_RUNTIMES_ARGS, FLATPAK_APP_RUNTIMES_ARGS]
+            .map(|args| self.commands.run(FLATPAK, args).ok().filter(|output| output.success));
+        if let [Some(runtimes), Some(app_runtimes)] = flatpak {
+            for (flatpak_ref, size) in unused_flatpak_runtimes(&runtimes.stdout, &app_runtimes.stdout) {
+                let item = CleanableItem::new(flatpak_ref, size.unwrap_or(0)).with_kind(ItemKind::FlatpakRuntime);
+                match size {
+                    Some(size) => {
+                        ctx.item_found(size);
+                        items.push(item);
+                    }
+                    None => items.push(item.with_unknown_size()),
+                }
+            }
+        }
+
+        if let Some(output) = self.commands.run(SNAP, SNAP_LIST_ARGS).ok().filter(|output| output.success) {
+            let snap_dir = self.platform.snap_dir().unwrap_or_default();
+
+            for revision in parse_removable_snap_revisions(&output.stdout) {
+                let path = snap_dir.join(revision.file_name());
+                let size = fs::metadata(&path).ok().map(|metadata| metadata.len());
+                let item = CleanableItem::new(path.to_string_lossy().to_string(), size.unwrap_or(0))
+                    .with_kind(ItemKind::SnapRevision);
+                match size {
+                    Some(size) => {
+                        ctx.item_found(size);
+                        items.push(item);
+                    }
+                    None => items.push(item.with_unknown_size()),
+                }
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Indica se a categoria pode ser oferecida nesta máquina.
+    /// Runtimes só aparecem com o Flatpak ou o snap instalados.
+    fn is_available(&self, category_type: CategoryType) -> bool {
+        match category_type {
+            CategoryType::UnusedRuntimes => {
+                [FLATPAK, SNAP]
+                    .iter()
+                    .any(|tool| self.commands.run(tool, &["--version"]).is_ok_and(|output| output.success))
+            }
+            _ => true,
+        }
+    }
+
+    /// Soma o espaço dos arquivos e subdiretórios, como o `du`.
+    fn calculate_directory_size(&self, path: &Path, ctx: &ScanContext) -> Result<DiskUsage> {
+        let mut usage = DiskUsage::default();
         
         if path.is_dir() {
-            for entry in WalkDir::new(path)
-                .max_depth(10)
-                .into_iter()
+            // Conteúdo excluído não é removido na limpeza, então não entra no tamanho
+            for entry in ctx
+                .walk(path, self.config.depth.directory_size)
+                .take_while(|_| ctx.is_active())
                 .filter_map(|e| e.ok())
-                .filter(|e| e.path().is_file())
+                .filter(|e| !e.file_type().is_symlink())
             {
                 if let Ok(metadata) = entry.metadata() {
-                    size += metadata.len();
+                    let entry_usage = ctx.usage(&metadata);
+                    // O tamanho aparente de um diretório não é conteúdo
+                    if entry.file_type().is_dir() {
+                        usage.on_disk += entry_usage.on_disk;
+                    } else {
+                        usage.add(entry_usage);
+                    }
                 }
             }
         }
         
-        Ok(size)
+        Ok(usage)
     }
 }
 
 impl ScannerRepository for FileSystemScannerRepository {
-    fn scan_system(&self) -> Result<ScanResult> {
+    fn scan_system_with_progress(
+        &self,
+        observer: &dyn ScanProgressObserver,
+        cancellation: &CancellationToken,
+    ) -> Result<ScanResult> {
         let mut result = ScanResult::new();
+        let category_types: Vec<CategoryType> = CategoryType::all()
+            .into_iter()
+            .filter(|&category_type| self.config.is_enabled(category_type) && self.is_available(category_type))
+            .collect();
+        let totals = ScanTotals::default();
+
+        // Escanear as categorias em paralelo, preservando a ordem original
+        let scanned = run_bounded(&category_types, MAX_SCAN_WORKERS, |&category_type| {
+            observer.on_progress(ScanProgress::CategoryStarted { category: category_type });
+
+            let ctx = ScanContext::new(category_type, observer, &totals, cancellation, &self.exclusions)
+                .with_same_file_system(self.config.same_file_system)
+                .with_claimed_roots(self.roots_claimed_elsewhere(category_type));
+            let items = self.scan_category_with(&ctx);
+            let truncation = ctx.truncation();
+
+            let completed_categories = totals.complete_category();
+            observer.on_progress(ScanProgress::CategoryFinished {
+                category: category_type,
+                completed_categories,
+                total_categories: category_types.len(),
+            });
+
+            items.map(|items| (items, truncation))
+        });
+
+        if cancellation.is_cancelled() {
+            return Err(anyhow!("Varredura cancelada"));
+        }
+
+        for (category_type, scanned_category) in category_types.iter().zip(scanned) {
+            let mut category = CleanableCategory::for_type(*category_type);
 
-        // Escanear cada categoria
-        for category_type in CategoryType::all() {
-            let items = self.scan_category(category_type)?;
-            let mut category = CleanableCategory::new(category_type.display_name().to_string());
-            
-            for item in items {
-                category.add_item(item);
+            // Uma categoria que falhou continua no resultado, vazia e com o motivo
+            match scanned_category {
+                Ok((items, truncation)) => {
+                    for item in items {
+                        category.add_item(item);
+                    }
+                    category.mark_truncated(truncation);
+                }
+                Err(e) => category.mark_failed(e.to_string()),
             }
-            
+
             result.add_category(category);
         }
 
@@ -287,13 +910,12 @@ impl ScannerRepository for FileSystemScannerRepository {
     }
 
     fn scan_category(&self, category_type: CategoryType) -> Result<Vec<CleanableItem>> {
-        match category_type {
-            CategoryType::LogFiles => self.scan_log_files(),
-            CategoryType::TemporaryFiles => self.scan_temp_files(),
-            CategoryType::DockerImages => self.scan_docker_images(),
-            CategoryType::DockerVolumes => self.scan_docker_volumes(),
-            CategoryType::DevelopmentPackages => self.scan_dev_packages(),
-        }
+        let totals = ScanTotals::default();
+        let cancellation = CancellationToken::new();
+        let ctx = ScanContext::new(category_type, &NoScanProgress, &totals, &cancellation, &self.exclusions)
+            .with_same_file_system(self.config.same_file_system)
+            .with_claimed_roots(self.roots_claimed_elsewhere(category_type));
+        self.scan_category_with(&ctx)
     }
 }
 
@@ -302,3 +924,853 @@ impl Default for FileSystemScannerRepository {
         Self::new()
     }
 }
+
+/// Diretório do projeto ao qual um artefato de build pertence.
+fn project_of(artifact: &Path) -> String {
+    artifact.parent().unwrap_or(artifact).to_string_lossy().to_string()
+}
+
+/// Executa `task` para cada entrada usando no máximo `max_workers` threads.
+/// Os resultados são devolvidos na mesma ordem das entradas.
+fn run_bounded<T, R, F>(inputs: &[T], max_workers: usize, task: F) -> Vec<Result<R>>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> Result<R> + Sync,
+{
+    let workers = max_workers.clamp(1, inputs.len().max(1));
+    let next_index = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            let sender = sender.clone();
+            let next_index = &next_index;
+            let task = &task;
+
+            scope.spawn(move || loop {
+                let index = next_index.fetch_add(1, Ordering::Relaxed);
+                let Some(input) = inputs.get(index) else {
+                    break;
+                };
+
+                if sender.send((index, task(input))).is_err() {
+                    break;
+                }
+            });
+        }
+    });
+    drop(sender);
+
+    let mut results: Vec<Option<Result<R>>> = inputs.iter().map(|_| None).collect();
+    for (index, result) in receiver {
+        results[index] = Some(result);
+    }
+
+    results
+        .into_iter()
+        .map(|result| result.unwrap_or_else(|| Err(anyhow::anyhow!("Varredura interrompida"))))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use crate::infrastructure::command::command_runner::{SystemCommandRunner, fake_program};
+    use crate::infrastructure::docker::docker_client::FakeDockerDaemon;
+    use crate::infrastructure::platform::{BrowserDirs, BrowserFamily};
+    use std::os::unix::fs::MetadataExt;
+    use std::path::PathBuf;
+    use std::time::{Duration, SystemTime};
+
+    /// Provedor de caminhos que aponta tudo para um diretório de teste.
+    struct FakePlatformPaths {
+        root: PathBuf,
+    }
+
+    impl PlatformPaths for FakePlatformPaths {
+        fn home(&self) -> &Path {
+            &self.root
+        }
+
+        fn log_dirs(&self) -> Vec<PathBuf> {
+            // Como `/tmp`, o diretório temporário também guarda logs
+            vec![self.root.join("logs"), self.root.join("cache")]
+        }
+
+        fn temp_dirs(&self) -> Vec<PathBuf> {
+            vec![self.root.join("cache")]
+        }
+
+        fn package_cache_dirs(&self) -> Vec<PathBuf> {
+            vec![self.root.join(".npm")]
+        }
+
+        fn cargo_home(&self) -> PathBuf {
+            self.root.join(".cargo")
+        }
+
+        fn crash_dump_dirs(&self) -> Vec<PathBuf> {
+            vec![self.root.join("coredump"), self.root.join("crash")]
+        }
+
+        fn snap_dir(&self) -> Option<PathBuf> {
+            Some(self.root.join("snaps"))
+        }
+
+        fn trash_dirs(&self) -> Vec<PathBuf> {
+            vec![self.root.join("Trash")]
+        }
+
+        fn browser_dirs(&self) -> Vec<BrowserDirs> {
+            vec![BrowserDirs {
+                browser: "Chrome",
+                family: BrowserFamily::Chromium,
+                data_dir: self.root.join("chrome"),
+                cache_dir: self.root.join("cache/chrome"),
+            }]
+        }
+    }
+
+    /// Cria um log fechado e sem escrita há um mês, que a varredura considera órfão.
+    fn write_old_log(path: &Path) {
+        fs::write(path, b"log line").unwrap();
+        fs::File::open(path)
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(30 * 24 * 60 * 60))
+            .unwrap();
+    }
+
+    /// Scanner com caminhos e ferramentas externas (em `root/bin`) falsos.
+    fn fake_scanner(root: &Path) -> FileSystemScannerRepository {
+        FileSystemScannerRepository::new()
+            .with_platform_paths(Arc::new(FakePlatformPaths { root: root.to_path_buf() }))
+            .with_command_runner(Arc::new(SystemCommandRunner::with_search_path(root.join("bin"))))
+    }
+
+    #[test]
+    fn should_scan_locations_from_platform_provider() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("logs")).unwrap();
+        fs::create_dir_all(dir.path().join("cache")).unwrap();
+        write_old_log(&dir.path().join("logs/app.log"));
+        fs::write(dir.path().join("cache/blob.bin"), b"cached").unwrap();
+
+        let repo = fake_scanner(dir.path());
+
+        let logs = repo.scan_category(CategoryType::LogFiles).unwrap();
+        assert_eq!(logs.len(), 1);
+        assert!(logs[0].path().ends_with("logs/app.log"));
+
+        let temp = repo.scan_category(CategoryType::TemporaryFiles).unwrap();
+        assert_eq!(temp.len(), 1);
+        assert!(temp[0].path().ends_with("cache/blob.bin"));
+    }
+
+    #[test]
+    fn should_leave_other_categories_files_out_of_temporary_files() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("chrome/Default")).unwrap();
+        fs::create_dir_all(dir.path().join("cache/chrome/Default/Cache")).unwrap();
+        fs::write(dir.path().join("cache/chrome/Default/Cache/data_0"), b"cached").unwrap();
+        write_old_log(&dir.path().join("cache/build.log"));
+        fs::write(dir.path().join("cache/blob.bin"), b"cached").unwrap();
+
+        let repo = fake_scanner(dir.path());
+        let temp = repo.scan_category(CategoryType::TemporaryFiles).unwrap();
+
+        assert_eq!(temp.len(), 1);
+        assert!(temp[0].path().ends_with("cache/blob.bin"));
+        assert_eq!(repo.scan_category(CategoryType::LogFiles).unwrap().len(), 1);
+        assert_eq!(repo.scan_category(CategoryType::BrowserCaches).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn should_not_walk_a_root_that_is_a_symlink() {
+        let dir = tempfile::tempdir().unwrap();
+        let mount = tempfile::tempdir().unwrap();
+        fs::write(mount.path().join("remote.bin"), b"remote").unwrap();
+        std::os::unix::fs::symlink(mount.path(), dir.path().join("cache")).unwrap();
+
+        let temp = fake_scanner(dir.path()).scan_category(CategoryType::TemporaryFiles).unwrap();
+
+        assert!(temp.is_empty());
+        assert!(mount.path().join("remote.bin").exists());
+    }
+
+    #[test]
+    fn should_skip_excluded_paths_and_marked_trees() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("logs/fixtures.log")).unwrap();
+        fs::create_dir_all(dir.path().join("logs/protegido")).unwrap();
+        write_old_log(&dir.path().join("logs/app.log"));
+        write_old_log(&dir.path().join("logs/fixtures.log/case.log"));
+        fs::write(dir.path().join("logs/protegido/.pccleanignore"), b"").unwrap();
+        write_old_log(&dir.path().join("logs/protegido/old.log"));
+
+        let exclusions = ExclusionRules::new(&["*.log/".to_string()], dir.path()).unwrap();
+        let logs = fake_scanner(dir.path())
+            .with_exclusions(exclusions)
+            .scan_category(CategoryType::LogFiles)
+            .unwrap();
+
+        assert_eq!(logs.len(), 1);
+        assert!(logs[0].path().ends_with("logs/app.log"));
+    }
+
+    #[test]
+    fn should_list_symlinks_without_following_them() {
+        let dir = tempfile::tempdir().unwrap();
+        let outside = dir.path().join("nfs");
+        fs::create_dir_all(&outside).unwrap();
+        fs::create_dir_all(dir.path().join("logs")).unwrap();
+        fs::write(outside.join("remote.log"), b"remote").unwrap();
+        std::os::unix::fs::symlink(&outside, dir.path().join("logs/share")).unwrap();
+        std::os::unix::fs::symlink(outside.join("remote.log"), dir.path().join("logs/latest.log")).unwrap();
+
+        let logs = fake_scanner(dir.path()).scan_category(CategoryType::LogFiles).unwrap();
+
+        assert_eq!(logs.len(), 1);
+        assert!(logs[0].path().ends_with("logs/latest.log"));
+        assert_eq!(logs[0].kind(), ItemKind::Symlink);
+    }
+
+    #[test]
+    fn should_list_only_items_trashed_long_enough_ago() {
+        let dir = tempfile::tempdir().unwrap();
+        let trash = dir.path().join("Trash");
+        fs::create_dir_all(trash.join("files/projeto")).unwrap();
+        fs::create_dir_all(trash.join("info")).unwrap();
+        fs::write(trash.join("files/projeto/main.rs"), b"fn main() {}").unwrap();
+        fs::write(trash.join("files/recente.txt"), b"novo").unwrap();
+        fs::write(
+            trash.join("info/projeto.trashinfo"),
+            "[Trash Info]\nPath=/home/dev/projeto\nDeletionDate=2020-01-01T00:00:00\n",
+        )
+        .unwrap();
+        let today = chrono::Local::now().format("%Y-%m-%dT%H:%M:%S");
+        fs::write(
+            trash.join("info/recente.txt.trashinfo"),
+            format!("[Trash Info]\nPath=/home/dev/recente.txt\nDeletionDate={}\n", today),
+        )
+        .unwrap();
+
+        let mut config = ScanConfig::default();
+        config.trash.min_age_days = 30;
+        let repo = FileSystemScannerRepository::with_config(config).with_platform_paths(Arc::new(
+            FakePlatformPaths { root: dir.path().to_path_buf() },
+        ));
+
+        let items = repo.scan_category(CategoryType::Trash).unwrap();
+
+        assert_eq!(items.len(), 1);
+        assert!(items[0].path().ends_with("Trash/files/projeto"));
+        assert_eq!(items[0].kind(), ItemKind::Directory);
+        assert_eq!(items[0].trashed_from(), Some("/home/dev/projeto"));
+        assert_eq!(fake_scanner(dir.path()).scan_category(CategoryType::Trash).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn should_list_one_item_per_browser_profile() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("chrome/Default/Code Cache")).unwrap();
+        fs::create_dir_all(dir.path().join("cache/chrome/Default/Cache")).unwrap();
+        fs::write(dir.path().join("chrome/Default/Code Cache/js"), b"compiled").unwrap();
+        fs::write(dir.path().join("cache/chrome/Default/Cache/data_0"), b"cached").unwrap();
+        fs::write(dir.path().join("chrome/Default/History"), b"visited").unwrap();
+
+        let items = fake_scanner(dir.path()).scan_category(CategoryType::BrowserCaches).unwrap();
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].path(), "Chrome · Default");
+        assert_eq!(items[0].apparent_size(), 14);
+        assert_eq!(items[0].parts().len(), 2);
+        assert!(items[0].parts().iter().all(|part| !part.ends_with("History")));
+    }
+
+    #[test]
+    fn should_list_stale_cargo_targets_and_registry_parts() {
+        let dir = tempfile::tempdir().unwrap();
+        let old_build = SystemTime::now() - Duration::from_secs(60 * 24 * 60 * 60);
+        for (project, built_at) in [("velho", old_build), ("novo", SystemTime::now())] {
+            let profile = dir.path().join("Projects").join(project).join("target/debug");
+            fs::create_dir_all(&profile).unwrap();
+            fs::write(dir.path().join("Projects").join(project).join("Cargo.toml"), "[package]\n").unwrap();
+            fs::write(profile.join("app"), b"binary").unwrap();
+            fs::File::create(profile.join(".cargo-lock")).unwrap().set_modified(built_at).unwrap();
+        }
+        fs::create_dir_all(dir.path().join("Projects/site/target")).unwrap();
+        fs::write(dir.path().join("Projects/site/target/index.html"), b"<html>").unwrap();
+        for part in ["registry/src/crate", "registry/cache/crate", "git/checkouts/repo"] {
+            fs::create_dir_all(dir.path().join(".cargo").join(part)).unwrap();
+            fs::write(dir.path().join(".cargo").join(part).join("lib.rs"), b"pub fn f() {}").unwrap();
+        }
+
+        let mut config = ScanConfig::default();
+        config.rust.target_min_age_days = 30;
+        let repo = FileSystemScannerRepository::with_config(config).with_platform_paths(Arc::new(
+            FakePlatformPaths { root: dir.path().to_path_buf() },
+        ));
+
+        let items = repo.scan_category(CategoryType::RustArtifacts).unwrap();
+        let paths: Vec<&str> = items.iter().map(|item| item.path()).collect();
+
+        assert_eq!(paths.len(), 4);
+        assert!(paths[0].ends_with("Projects/velho/target"));
+        assert_eq!(items[0].modified_age(SystemTime::now()).unwrap().as_secs() / 86_400, 60);
+        assert!(paths[1].ends_with(".cargo/registry/src"));
+        assert!(paths[2].ends_with(".cargo/registry/cache"));
+        assert!(paths[3].ends_with(".cargo/git/checkouts"));
+    }
+
+    #[test]
+    fn should_measure_journal_with_journalctl() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = fake_scanner(dir.path());
+        assert!(repo.scan_category(CategoryType::SystemJournal).unwrap().is_empty());
+
+        fs::create_dir_all(dir.path().join("bin")).unwrap();
+        fake_program(
+            &dir.path().join("bin"),
+            "journalctl",
+            "echo 'Archived and active journals take up 1.5G in the file system.'",
+        );
+        let items = repo.scan_category(CategoryType::SystemJournal).unwrap();
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].kind(), ItemKind::SystemJournal);
+        assert_eq!(items[0].size_in_bytes(), (1536 - 500) * 1024 * 1024);
+        assert!(items[0].path().contains("1.50 GB"));
+    }
+
+    #[test]
+    fn should_offer_rotated_and_orphaned_logs_and_truncate_active_ones_on_request() {
+        let dir = tempfile::tempdir().unwrap();
+        let logs = dir.path().join("logs");
+        fs::create_dir_all(&logs).unwrap();
+        for name in ["app.log.1", "app.log.2.gz", "app-2024-01-01.log", "antigo.log"] {
+            write_old_log(&logs.join(name));
+        }
+        write_old_log(&logs.join("app.log"));
+        let _held_by_daemon = fs::OpenOptions::new().append(true).open(logs.join("app.log")).unwrap();
+        fs::write(logs.join("recente.log"), b"log line").unwrap();
+
+        let mut items = fake_scanner(dir.path()).scan_category(CategoryType::LogFiles).unwrap();
+        items.sort_by(|a, b| a.path().cmp(b.path()));
+        let states: Vec<(&str, Option<LogState>)> = items
+            .iter()
+            .map(|item| (item.path().rsplit('/').next().unwrap(), item.log_state()))
+            .collect();
+
+        assert_eq!(
+            states,
+            vec![
+                ("antigo.log", Some(LogState::Orphaned)),
+                ("app-2024-01-01.log", Some(LogState::Rotated)),
+                ("app.log.1", Some(LogState::Rotated)),
+                ("app.log.2.gz", Some(LogState::Rotated)),
+            ]
+        );
+
+        let mut config = ScanConfig::default();
+        config.logs.truncate_active = true;
+        let repo = FileSystemScannerRepository::with_config(config)
+            .with_platform_paths(Arc::new(FakePlatformPaths { root: dir.path().to_path_buf() }));
+        let active: Vec<_> = repo
+            .scan_category(CategoryType::LogFiles)
+            .unwrap()
+            .into_iter()
+            .filter(|item| item.truncates_in_place())
+            .map(|item| item.path().rsplit('/').next().unwrap().to_string())
+            .collect();
+
+        assert_eq!(active.len(), 2);
+        assert!(active.contains(&"app.log".to_string()));
+        assert!(active.contains(&"recente.log".to_string()));
+    }
+
+    #[test]
+    fn should_list_crash_dumps_with_executable_and_crash_time() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("bin")).unwrap();
+        fake_program(
+            &dir.path().join("bin"),
+            "coredumpctl",
+            r#"echo '[{"time":1700000000000000,"pid":4242,"uid":1000,"gid":1000,"sig":11,"corefile":"present","exe":"/usr/bin/servidor","size":4096}]'"#,
+        );
+        fs::create_dir_all(dir.path().join("coredump")).unwrap();
+        fs::write(dir.path().join("coredump/core.servidor.1000.9f2c.4242.1700000000000100.zst"), b"dump").unwrap();
+        fs::create_dir_all(dir.path().join("crash")).unwrap();
+        fs::write(dir.path().join("crash/_usr_bin_foo.1000.crash"), "ExecutablePath: /usr/bin/foo\n").unwrap();
+        fs::create_dir_all(dir.path().join("Projects/app")).unwrap();
+        fs::write(dir.path().join("Projects/app/core.777"), crate::infrastructure::repositories::crash_dumps::elf_core_header()).unwrap();
+        fs::write(dir.path().join("Projects/app/core"), b"nao sou um dump").unwrap();
+
+        let mut items = fake_scanner(dir.path()).scan_category(CategoryType::CrashDumps).unwrap();
+        items.sort_by(|a, b| a.path().cmp(b.path()));
+        let found: Vec<(&str, Option<&str>)> = items
+            .iter()
+            .map(|item| (item.path().rsplit('/').next().unwrap(), item.crashed_exe()))
+            .collect();
+
+        assert_eq!(
+            found,
+            vec![
+                ("core.777", None),
+                ("core.servidor.1000.9f2c.4242.1700000000000100.zst", Some("/usr/bin/servidor")),
+                ("_usr_bin_foo.1000.crash", Some("/usr/bin/foo")),
+            ]
+        );
+        let crashed_days_ago = items[1].crash_age(SystemTime::now()).unwrap().as_secs() / 86_400;
+        let expected = SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs() / 86_400 - 1_700_000_000 / 86_400;
+        assert!(crashed_days_ago.abs_diff(expected) <= 1);
+    }
+
+    #[test]
+    fn should_list_unused_runtimes_and_hide_category_without_tools() {
+        let dir = tempfile::tempdir().unwrap();
+        let bin = dir.path().join("bin");
+        fs::create_dir_all(&bin).unwrap();
+        let config = ScanConfig {
+            enabled_categories: vec![CategoryType::UnusedRuntimes.identifier().to_string()],
+            ..ScanConfig::default()
+        };
+        let repo = FileSystemScannerRepository::with_config(config)
+            .with_platform_paths(Arc::new(FakePlatformPaths { root: dir.path().to_path_buf() }))
+            .with_command_runner(Arc::new(SystemCommandRunner::with_search_path(&bin)));
+
+        assert!(repo.scan_system_with_progress(&NoScanProgress, &CancellationToken::new()).unwrap().categories().is_empty());
+
+        // Um flatpak quebrado não torna a categoria disponível
+        fake_program(&bin, "flatpak", "exit 1");
+        assert!(repo.scan_system_with_progress(&NoScanProgress, &CancellationToken::new()).unwrap().categories().is_empty());
+
+        fake_program(
+            &bin,
+            "flatpak",
+            r#"case "$1 $2" in
+  "--version ") ;;
+  "list --runtime") printf 'org.gnome.Platform/x86_64/43\t1.2 GB\norg.gnome.Platform/x86_64/45\t1.1 GB\norg.gnome.Sdk/x86_64/45\t\n' ;;
+  "list --app") printf 'org.gnome.Platform/x86_64/45\n' ;;
+  *) exit 1 ;;
+esac"#,
+        );
+        fake_program(
+            &bin,
+            "snap",
+            r#"printf 'Name    Version   Rev   Tracking       Publisher   Notes\n'
+printf 'core18  20230901  2790  latest/stable  canonical    base,disabled\n'
+printf 'core18  20231027  2796  latest/stable  canonical    base\n'
+printf 'core20  20230801  1974  latest/stable  canonical    base,disabled\n'
+printf 'core20  20231123  2105  latest/stable  canonical    base\n'"#,
+        );
+        fs::create_dir_all(dir.path().join("snaps")).unwrap();
+        fs::write(dir.path().join("snaps/core18_2790.snap"), vec![0u8; 4096]).unwrap();
+
+        let result = repo.scan_system_with_progress(&NoScanProgress, &CancellationToken::new()).unwrap();
+        let items = result.categories()[0].items();
+        let found: Vec<(&str, u64, ItemKind)> = items
+            .iter()
+            .map(|item| (item.path(), item.size_in_bytes(), item.kind()))
+            .collect();
+        let snap = dir.path().join("snaps/core18_2790.snap").to_string_lossy().to_string();
+        let missing_snap = dir.path().join("snaps/core20_1974.snap").to_string_lossy().to_string();
+
+        assert_eq!(
+            found,
+            vec![
+                ("org.gnome.Platform/x86_64/43", 1_200_000_000, ItemKind::FlatpakRuntime),
+                ("org.gnome.Sdk/x86_64/45", 0, ItemKind::FlatpakRuntime),
+                (snap.as_str(), 4096, ItemKind::SnapRevision),
+                (missing_snap.as_str(), 0, ItemKind::SnapRevision),
+            ]
+        );
+        // Uma revisão sem `.snap` legível tem tamanho desconhecido, como o runtime sem tamanho
+        assert!(items[2].is_size_known());
+        assert!(!items[3].is_size_known());
+    }
+
+    #[test]
+    fn should_list_docker_images_with_sizes_from_the_engine_api() {
+        let dir = tempfile::tempdir().unwrap();
+        let daemon = FakeDockerDaemon::start(
+            dir.path(),
+            &[("GET /images/json", 200, r#"[{"Id":"sha256:aaaabbbbccccdddd","Size":2048},{"Id":"sha256:eeeeffff00001111","Size":0}]"#)],
+        );
+        let repo = fake_scanner(dir.path()).with_docker(daemon.client.clone());
+
+        let items = repo.scan_category(CategoryType::DockerImages).unwrap();
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].path(), "Docker Image: aaaabbbbcccc");
+        assert_eq!(items[0].docker_id(), Some("sha256:aaaabbbbccccdddd"));
+        assert_eq!(items[0].size_in_bytes(), 2048);
+        assert_eq!(daemon.requests().len(), 1);
+
+        let down = fake_scanner(dir.path()).with_docker(DockerClient::with_socket(&dir.path().join("parado.sock")));
+        let error = down.scan_category(CategoryType::DockerVolumes).unwrap_err();
+        assert!(error.to_string().starts_with("Docker indisponível"));
+    }
+
+    #[test]
+    fn should_keep_failed_categories_with_their_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = ScanConfig {
+            enabled_categories: vec![CategoryType::DockerVolumes.identifier().to_string()],
+            ..ScanConfig::default()
+        };
+        let repo = FileSystemScannerRepository::with_config(config)
+            .with_platform_paths(Arc::new(FakePlatformPaths { root: dir.path().to_path_buf() }))
+            .with_docker(DockerClient::with_socket(&dir.path().join("parado.sock")));
+
+        let result = repo.scan_system_with_progress(&NoScanProgress, &CancellationToken::new()).unwrap();
+
+        assert_eq!(result.categories().len(), 1);
+        assert!(result.categories()[0].is_empty());
+        assert!(result.categories()[0].scan_error().is_some_and(|error| error.starts_with("Docker indisponível")));
+    }
+
+    #[test]
+    fn should_measure_docker_volumes_and_flag_unknown_sizes() {
+        let dir = tempfile::tempdir().unwrap();
+        let daemon = FakeDockerDaemon::start(
+            dir.path(),
+            &[
+                ("GET /volumes", 200, r#"{"Volumes":[{"Name":"dados"},{"Name":"remoto"}],"Warnings":[]}"#),
+                (
+                    "GET /system/df",
+                    200,
+                    r#"{"Volumes":[{"Name":"dados","UsageData":{"Size":52428800,"RefCount":0}},{"Name":"remoto","UsageData":{"Size":-1,"RefCount":-1}}]}"#,
+                ),
+            ],
+        );
+        let repo = fake_scanner(dir.path()).with_docker(daemon.client.clone());
+
+        let items = repo.scan_category(CategoryType::DockerVolumes).unwrap();
+        let mut category = CleanableCategory::for_type(CategoryType::DockerVolumes);
+        for item in items.iter().cloned() {
+            category.add_item(item);
+        }
+
+        assert_eq!(items.len(), 2);
+        assert!(items[0].is_size_known());
+        assert!(!items[1].is_size_known());
+        assert_eq!(items[1].docker_id(), Some("remoto"));
+        assert_eq!(category.item_count(), 2);
+        assert_eq!(category.unknown_size_count(), 1);
+        assert_eq!(category.total_size(), 52_428_800);
+    }
+
+    #[test]
+    fn should_list_build_cache_and_stopped_containers_from_the_engine_api() {
+        let dir = tempfile::tempdir().unwrap();
+        let daemon = FakeDockerDaemon::start(
+            dir.path(),
+            &[
+                (
+                    "GET /system/df",
+                    200,
+                    r#"{"BuildCache":[{"ID":"k3x9","Size":5000,"InUse":false,"CreatedAt":"2024-01-01T10:00:00Z","LastUsedAt":"2024-02-01T10:00:00Z"}]}"#,
+                ),
+                (
+                    "GET /containers/json",
+                    200,
+                    r#"[
+                        {"Id":"3f2a1b4c5d6e7f80","Names":["/web-old"],"SizeRw":1234,"Created":1700000000},
+                        {"Id":"9e8d7c6b5a493827","Names":["/db"],"Created":1700000000},
+                        {"Id":"0a1b2c3d4e5f6071","Names":["/empty"],"SizeRw":0,"Created":1700000000}
+                    ]"#,
+                ),
+            ],
+        );
+        let repo = fake_scanner(dir.path()).with_docker(daemon.client.clone());
+
+        let cache = repo.scan_category(CategoryType::DockerBuildCache).unwrap();
+        assert_eq!(cache[0].path(), "Docker Build Cache: k3x9");
+        assert_eq!(cache[0].docker_id(), Some("k3x9"));
+        assert_eq!(cache[0].size_in_bytes(), 5000);
+        assert_eq!(cache[0].accessed(), Some(std::time::UNIX_EPOCH + Duration::from_secs(1_706_781_600)));
+
+        let containers = repo.scan_category(CategoryType::DockerContainers).unwrap();
+        assert_eq!(containers.len(), 2);
+        assert_eq!(containers[0].path(), "Docker Container: 3f2a1b4c5d6e (web-old)");
+        assert_eq!(containers[0].docker_id(), Some("3f2a1b4c5d6e7f80"));
+        assert_eq!(containers[0].size_in_bytes(), 1234);
+        assert_eq!(containers[0].kind(), ItemKind::DockerContainer);
+
+        // Sem `SizeRw`, o contêiner continua listado, mas fora dos totais
+        assert_eq!(containers[1].path(), "Docker Container: 9e8d7c6b5a49 (db)");
+        assert!(!containers[1].is_size_known());
+        assert!(containers[1].is_significant());
+    }
+
+    #[test]
+    fn should_record_kind_times_and_owner_of_scanned_items() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("logs")).unwrap();
+        write_old_log(&dir.path().join("logs/app.log"));
+        let owner = fs::metadata(dir.path().join("logs/app.log")).unwrap().uid();
+
+        let logs = fake_scanner(dir.path()).scan_category(CategoryType::LogFiles).unwrap();
+
+        assert_eq!(logs[0].kind(), ItemKind::File);
+        assert_eq!(logs[0].owner_uid(), Some(owner));
+        assert!(logs[0].modified_age(SystemTime::now()).is_some());
+    }
+
+    #[test]
+    fn should_keep_input_order_when_running_in_parallel() {
+        let inputs = vec![30u64, 0, 20, 5, 10];
+
+        let results = run_bounded(&inputs, 3, |&delay| {
+            thread::sleep(Duration::from_millis(delay));
+            Ok(delay)
+        });
+
+        let values: Vec<u64> = results.into_iter().map(|r| r.unwrap()).collect();
+        assert_eq!(values, inputs);
+    }
+
+    #[test]
+    fn should_not_exceed_worker_limit() {
+        let inputs: Vec<usize> = (0..8).collect();
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+
+        run_bounded(&inputs, 2, |_| {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(10));
+            running.fetch_sub(1, Ordering::SeqCst);
+            Ok(())
+        });
+
+        assert!(peak.load(Ordering::SeqCst) <= 2);
+    }
+
+    #[test]
+    fn should_fail_when_scan_is_cancelled() {
+        let repo = FileSystemScannerRepository::new();
+        let cancellation = CancellationToken::new();
+        cancellation.cancel();
+
+        let result = repo.scan_system_with_progress(&NoScanProgress, &cancellation);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_mark_category_truncated_when_limit_is_reached() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("cache")).unwrap();
+        for name in ["a.bin", "b.bin", "c.bin"] {
+            fs::write(dir.path().join("cache").join(name), b"1234").unwrap();
+        }
+
+        let mut config = ScanConfig {
+            enabled_categories: vec![CategoryType::TemporaryFiles.identifier().to_string()],
+            ..ScanConfig::default()
+        };
+        config.limits.temp_files_per_dir = Some(1);
+        let repo = FileSystemScannerRepository::with_config(config).with_platform_paths(Arc::new(
+            FakePlatformPaths { root: dir.path().to_path_buf() },
+        ));
+
+        let result = repo.scan_system_with_progress(&NoScanProgress, &CancellationToken::new()).unwrap();
+        let category = &result.categories()[0];
+        assert_eq!(category.item_count(), 1);
+
+        let allocated = fs::metadata(dir.path().join("cache/a.bin")).unwrap().blocks() * 512;
+        let truncation = category.truncation().unwrap();
+        assert_eq!(truncation.skipped_items(), 2);
+        assert_eq!(truncation.estimated_skipped_bytes(), allocated * 2);
+    }
+
+    #[test]
+    fn should_measure_allocated_blocks_once_per_inode() {
+        let dir = tempfile::tempdir().unwrap();
+        let modules = dir.path().join("Projects/app/node_modules");
+        fs::create_dir_all(&modules).unwrap();
+        fs::write(dir.path().join("Projects/app/package.json"), "{}").unwrap();
+        fs::write(modules.join("index.js"), vec![b'x'; 8192]).unwrap();
+        fs::hard_link(modules.join("index.js"), modules.join("linked.js")).unwrap();
+        fs::File::create(modules.join("sparse.img")).unwrap().set_len(1 << 20).unwrap();
+
+        let items = fake_scanner(dir.path()).scan_category(CategoryType::DevelopmentPackages).unwrap();
+        let expected_on_disk: u64 = [&modules, &modules.join("index.js"), &modules.join("sparse.img")]
+            .iter()
+            .map(|path| fs::metadata(path).unwrap().blocks() * 512)
+            .sum();
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].apparent_size(), 8192 * 2 + (1 << 20));
+        assert_eq!(items[0].size_in_bytes(), expected_on_disk);
+        assert!(items[0].size_in_bytes() < items[0].apparent_size());
+    }
+
+    #[test]
+    fn should_list_every_node_modules_without_a_limit() {
+        let dir = tempfile::tempdir().unwrap();
+        for project in ["one", "two", "three"] {
+            let modules = dir.path().join("Projects").join(project).join("node_modules/pkg");
+            fs::create_dir_all(modules.join("node_modules/nested")).unwrap();
+            fs::write(modules.join("index.js"), b"module.exports = 1;").unwrap();
+            fs::write(dir.path().join("Projects").join(project).join("package.json"), "{}").unwrap();
+        }
+
+        let repo = fake_scanner(dir.path());
+        let items = repo.scan_category(CategoryType::DevelopmentPackages).unwrap();
+
+        let node_modules: Vec<_> = items
+            .iter()
+            .filter(|item| item.path().ends_with("node_modules"))
+            .collect();
+        assert_eq!(node_modules.len(), 3);
+    }
+
+    #[test]
+    fn should_record_project_and_ecosystem_of_build_artifacts() {
+        let dir = tempfile::tempdir().unwrap();
+        let projects = dir.path().join("Projects");
+        for (project, marker, artifact) in [
+            ("api", "pyproject.toml", ".venv/lib"),
+            ("api", "pyproject.toml", "__pycache__"),
+            ("loja", "composer.json", "vendor/pkg"),
+            ("app", "build.gradle", "build/outputs"),
+            ("notas", "README.md", "build/html"),
+        ] {
+            fs::create_dir_all(projects.join(project).join(artifact)).unwrap();
+            fs::write(projects.join(project).join(marker), "").unwrap();
+            fs::write(projects.join(project).join(artifact).join("data"), b"gerado").unwrap();
+        }
+
+        let mut items = fake_scanner(dir.path()).scan_category(CategoryType::DevelopmentPackages).unwrap();
+        items.sort_by(|a, b| a.path().cmp(b.path()));
+        let found: Vec<(&str, Option<&str>, Option<Ecosystem>)> = items
+            .iter()
+            .map(|item| (item.path(), item.project(), item.ecosystem()))
+            .collect();
+        let project = |name: &str| projects.join(name).to_string_lossy().to_string();
+
+        assert_eq!(found.len(), 4);
+        assert_eq!(found[0].1, Some(project("api").as_str()));
+        assert_eq!(found[0].2, Some(Ecosystem::Python));
+        assert!(found[1].0.ends_with("api/__pycache__"));
+        assert_eq!(found[2].1, Some(project("app").as_str()));
+        assert_eq!(found[2].2, Some(Ecosystem::Gradle));
+        assert_eq!(found[3].2, Some(Ecosystem::Php));
+    }
+
+    #[test]
+    fn should_list_only_artifacts_of_stale_projects() {
+        let dir = tempfile::tempdir().unwrap();
+        let now = SystemTime::now();
+        let day = Duration::from_secs(24 * 60 * 60);
+        for (project, lockfile_age) in [("parado", 45), ("ativo", 1)] {
+            let root = dir.path().join("Projects").join(project);
+            fs::create_dir_all(root.join("node_modules/pkg")).unwrap();
+            fs::write(root.join("node_modules/pkg/index.js"), b"module.exports = 1;").unwrap();
+            fs::File::create(root.join("package.json")).unwrap().set_modified(now - day * 90).unwrap();
+            fs::File::create(root.join("package-lock.json")).unwrap().set_modified(now - day * lockfile_age).unwrap();
+        }
+
+        let mut config = ScanConfig::default();
+        config.dev_packages.stale_project_days = 30;
+        let repo = FileSystemScannerRepository::with_config(config).with_platform_paths(Arc::new(
+            FakePlatformPaths { root: dir.path().to_path_buf() },
+        ));
+
+        let items: Vec<_> = repo
+            .scan_category(CategoryType::DevelopmentPackages)
+            .unwrap()
+            .into_iter()
+            .filter(|item| item.project().is_some())
+            .collect();
+
+        assert_eq!(items.len(), 1);
+        assert!(items[0].path().ends_with("parado/node_modules"));
+        assert_eq!(items[0].project_idle_age(now).map(|idle| idle.as_secs() / 86_400), Some(45));
+    }
+
+    #[test]
+    fn should_not_read_project_activity_when_idle_policy_is_off() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("Projects/ativo");
+        fs::create_dir_all(root.join("node_modules/pkg")).unwrap();
+        fs::write(root.join("node_modules/pkg/index.js"), b"module.exports = 1;").unwrap();
+        fs::write(root.join("package.json"), b"{}").unwrap();
+
+        let mut config = ScanConfig::default();
+        config.dev_packages.stale_project_days = 0;
+        let repo = FileSystemScannerRepository::with_config(config).with_platform_paths(Arc::new(
+            FakePlatformPaths { root: dir.path().to_path_buf() },
+        ));
+
+        let items: Vec<_> = repo
+            .scan_category(CategoryType::DevelopmentPackages)
+            .unwrap()
+            .into_iter()
+            .filter(|item| item.project().is_some())
+            .collect();
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].project_idle_age(SystemTime::now()), None);
+    }
+
+    #[test]
+    fn should_list_old_large_files_sorted_by_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let old = SystemTime::now() - Duration::from_secs(90 * 24 * 60 * 60);
+        for (name, size) in [("small.iso", 64usize), ("big.iso", 256), ("medium.iso", 128)] {
+            let file = fs::File::create(dir.path().join(name)).unwrap();
+            file.set_len(size as u64).unwrap();
+            file.set_modified(old).unwrap();
+        }
+        fs::write(dir.path().join("recent.iso"), vec![0u8; 512]).unwrap();
+        fs::write(dir.path().join("tiny.txt"), b"x").unwrap();
+
+        let mut config = ScanConfig::default();
+        config.large_files.min_size_bytes = 100;
+        config.large_files.min_age_days = 30;
+        config.large_files.age_basis = AgeBasis::Modified;
+        let repo = FileSystemScannerRepository::with_config(config).with_platform_paths(Arc::new(
+            FakePlatformPaths { root: dir.path().to_path_buf() },
+        ));
+
+        let items = repo.scan_category(CategoryType::LargeFiles).unwrap();
+        let names: Vec<_> = items
+            .iter()
+            .map(|item| Path::new(item.path()).file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(names, ["big.iso", "medium.iso"]);
+    }
+
+    #[test]
+    fn should_only_scan_enabled_categories() {
+        let config = ScanConfig {
+            enabled_categories: Vec::new(),
+            ..ScanConfig::default()
+        };
+        let repo = FileSystemScannerRepository::with_config(config);
+
+        let result = repo.scan_system_with_progress(&NoScanProgress, &CancellationToken::new()).unwrap();
+        assert!(result.categories().is_empty());
+    }
+
+    #[test]
+    fn should_keep_successful_results_when_one_task_fails() {
+        let inputs = vec![1, 2, 3];
+
+        let results = run_bounded(&inputs, 2, |&n| {
+            if n == 2 {
+                Err(anyhow::anyhow!("falhou"))
+            } else {
+                Ok(n)
+            }
+        });
+
+        assert_eq!(*results[0].as_ref().unwrap(), 1);
+        assert!(results[1].is_err());
+        assert_eq!(*results[2].as_ref().unwrap(), 3);
+    }
+}
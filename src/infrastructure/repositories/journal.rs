@@ -0,0 +1,77 @@
+use crate::infrastructure::config::JournalConfig;
+
+/// Ferramenta do systemd que mede e reduz o journal.
+pub(super) const JOURNALCTL: &str = "journalctl";
+
+/// Interpreta a saída do `journalctl --disk-usage`, como
+/// `Archived and active journals take up 1.5G in the file system.`
+pub(super) fn parse_disk_usage(output: &str) -> Option<u64> {
+    let (_, rest) = output.split_once("take up ")?;
+    parse_size(rest.split_whitespace().next()?)
+}
+
+/// Converte tamanhos no formato do systemd (`512B`, `8.0M`, `1.5G`), em potências de 1024.
+fn parse_size(value: &str) -> Option<u64> {
+    let split = value.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let exponent = match unit {
+        "" | "B" => 0,
+        "K" => 1,
+        "M" => 2,
+        "G" => 3,
+        "T" => 4,
+        "P" => 5,
+        _ => return None,
+    };
+
+    let number: f64 = number.parse().ok()?;
+    Some((number * 1024f64.powi(exponent)) as u64)
+}
+
+/// Argumentos do `journalctl` que aplicam os limites configurados.
+/// Sem nenhum limite não há o que reduzir.
+pub(super) fn vacuum_args(config: &JournalConfig) -> Vec<String> {
+    let mut args = Vec::new();
+    if config.vacuum_size_mb > 0 {
+        args.push(format!("--vacuum-size={}M", config.vacuum_size_mb));
+    }
+    if config.vacuum_time_days > 0 {
+        args.push(format!("--vacuum-time={}d", config.vacuum_time_days));
+    }
+    args
+}
+
+/// Estimativa do espaço liberado: o que passa do limite de tamanho.
+/// Só com o limite de tempo, o uso total é o teto.
+pub(super) fn reclaimable_estimate(usage: u64, config: &JournalConfig) -> u64 {
+    if config.vacuum_size_mb > 0 {
+        usage.saturating_sub(config.vacuum_size_mb * 1024 * 1024)
+    } else {
+        usage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_disk_usage_output() {
+        assert_eq!(
+            parse_disk_usage("Archived and active journals take up 1.5G in the file system.\n"),
+            Some(1536 * 1024 * 1024)
+        );
+        assert_eq!(parse_disk_usage("Journals take up 8.0M on disk.\n"), Some(8 * 1024 * 1024));
+        assert_eq!(parse_disk_usage("No journal files were found.\n"), None);
+    }
+
+    #[test]
+    fn should_build_vacuum_args_from_config() {
+        let config = JournalConfig { vacuum_size_mb: 200, vacuum_time_days: 14 };
+        assert_eq!(vacuum_args(&config), ["--vacuum-size=200M", "--vacuum-time=14d"]);
+        assert_eq!(reclaimable_estimate(300 * 1024 * 1024, &config), 100 * 1024 * 1024);
+
+        let disabled = JournalConfig { vacuum_size_mb: 0, vacuum_time_days: 0 };
+        assert!(vacuum_args(&disabled).is_empty());
+    }
+}
@@ -0,0 +1,122 @@
+use crate::domain::value_objects::LogState;
+use super::open_files::OpenFiles;
+use std::fs::Metadata;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+/// Extensões de logs comprimidos pela rotação.
+const COMPRESSED_EXTENSIONS: &[&str] = &["gz", "xz", "bz2", "zst", "lz4"];
+
+/// Classifica um log encontrado na varredura.
+/// Sem a lista de arquivos abertos (sem procfs), todo log não rotacionado é
+/// tratado como ativo; o mesmo vale para logs escritos há menos de `orphan_after`.
+pub(super) fn classify_log(
+    path: &Path,
+    metadata: &Metadata,
+    open_files: Option<&OpenFiles>,
+    now: SystemTime,
+    orphan_after: Duration,
+) -> LogState {
+    if path.file_name().is_some_and(|name| is_rotated_name(&name.to_string_lossy())) {
+        return LogState::Rotated;
+    }
+    // Um link nunca é truncado: a limpeza remove só o link
+    if metadata.file_type().is_symlink() {
+        return LogState::Orphaned;
+    }
+
+    let held_open = open_files.is_none_or(|open| open.contains(path));
+    let recently_written = metadata
+        .modified()
+        .ok()
+        .and_then(|modified| now.duration_since(modified).ok())
+        .is_none_or(|age| age < orphan_after);
+
+    if held_open || recently_written {
+        LogState::Active
+    } else {
+        LogState::Orphaned
+    }
+}
+
+/// Reconhece nomes gerados pela rotação: `app.log.1`, `app.log.2.gz`,
+/// `app.log-20240101`, `app.1.log` e `app-2024-01-01.log`.
+fn is_rotated_name(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    if name
+        .rsplit_once('.')
+        .is_some_and(|(_, extension)| COMPRESSED_EXTENSIONS.contains(&extension))
+    {
+        return true;
+    }
+
+    let Some(index) = name.rfind(".log") else {
+        return false;
+    };
+    let (stem, rest) = (&name[..index], &name[index + ".log".len()..]);
+
+    // Qualquer sufixo depois do `.log` (`.1`, `-20240101`, `.old`) é uma cópia antiga
+    if rest.starts_with(['.', '-', '_']) {
+        return true;
+    }
+    rest.is_empty() && (has_date_suffix(stem) || has_index_suffix(stem))
+}
+
+/// `app-2024-01-01` ou `app_20240101`.
+fn has_date_suffix(stem: &str) -> bool {
+    let bytes = stem.as_bytes();
+    let dashed = bytes.len() > 10
+        && bytes[bytes.len() - 10..]
+            .iter()
+            .enumerate()
+            .all(|(i, byte)| if i == 4 || i == 7 { *byte == b'-' } else { byte.is_ascii_digit() });
+    let compact = bytes.len() > 8 && bytes[bytes.len() - 8..].iter().all(u8::is_ascii_digit);
+    let separated = |len: usize| matches!(bytes[bytes.len() - len - 1], b'-' | b'_' | b'.');
+
+    (dashed && separated(10)) || (compact && separated(8))
+}
+
+/// `app.1`: índice numérico da rotação antes da extensão.
+fn has_index_suffix(stem: &str) -> bool {
+    stem.rsplit_once('.')
+        .is_some_and(|(_, index)| !index.is_empty() && index.bytes().all(|byte| byte.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{self, File};
+
+    #[test]
+    fn should_recognise_rotated_names() {
+        for name in ["app.log.1", "app.log.2.gz", "app.log-20240101", "app.1.log", "app-2024-01-01.log", "syslog.3.xz"] {
+            assert!(is_rotated_name(name), "{}", name);
+        }
+        for name in ["app.log", "node-18.log", "error.LOG", "catalog.log"] {
+            assert!(!is_rotated_name(name), "{}", name);
+        }
+    }
+
+    #[test]
+    fn should_classify_open_and_recent_logs_as_active() {
+        let dir = tempfile::tempdir().unwrap();
+        let now = SystemTime::now();
+        let week = Duration::from_secs(7 * 24 * 60 * 60);
+        let dir = dir.path().canonicalize().unwrap();
+        let (open_log, old_log, rotated_log) = (dir.join("open.log"), dir.join("old.log"), dir.join("old.log.1"));
+        for path in [&open_log, &old_log, &rotated_log] {
+            File::create(path).unwrap().set_modified(now - week * 2).unwrap();
+        }
+        let _held = File::open(&open_log).unwrap();
+        let open = OpenFiles::snapshot().unwrap();
+        let classify = |path: &Path, open: Option<&OpenFiles>| {
+            classify_log(path, &fs::symlink_metadata(path).unwrap(), open, now, week)
+        };
+
+        assert_eq!(classify(&open_log, Some(&open)), LogState::Active);
+        assert_eq!(classify(&old_log, Some(&open)), LogState::Orphaned);
+        assert_eq!(classify(&rotated_log, Some(&open)), LogState::Rotated);
+        assert_eq!(classify(&old_log, None), LogState::Active);
+        assert_eq!(classify_log(&old_log, &fs::metadata(&old_log).unwrap(), Some(&open), now, week * 3), LogState::Active);
+    }
+}
@@ -1,5 +1,17 @@
 pub mod filesystem_scanner_repository;
 pub mod filesystem_cleaner_repository;
+mod scan_context;
+mod duplicate_file_finder;
+mod trash;
+mod browser_cache_finder;
+mod cargo_artifacts;
+mod build_artifacts;
+mod journal;
+mod open_files;
+mod log_rotation;
+mod crash_dumps;
+mod app_runtimes;
+mod docker_items;
 
 pub use filesystem_scanner_repository::FileSystemScannerRepository;
 pub use filesystem_cleaner_repository::FileSystemCleanerRepository;
@@ -0,0 +1,135 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Raiz do procfs do Linux.
+const PROC_ROOT: &str = "/proc";
+
+/// Sufixo que o kernel acrescenta a caminhos já removidos.
+const DELETED_SUFFIX: &str = " (deleted)";
+
+/// Processo que mantém um arquivo aberto ou mapeado em memória.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct FileHolder {
+    pub(super) pid: u32,
+    pub(super) name: String,
+}
+
+/// Arquivos em uso pelos processos visíveis, lidos de `/proc/<pid>/fd` e
+/// `/proc/<pid>/maps`. Processos de outros usuários não deixam ler essas
+/// entradas e ficam de fora, salvo quando o programa roda como root.
+#[derive(Debug, Default)]
+pub(super) struct OpenFiles {
+    /// Ordenado para que tudo abaixo de um diretório fique contíguo.
+    holders: BTreeMap<PathBuf, Vec<FileHolder>>,
+}
+
+impl OpenFiles {
+    /// Fotografa os arquivos em uso agora.
+    /// Retorna `None` quando não há procfs (ex.: macOS).
+    pub(super) fn snapshot() -> Option<Self> {
+        Self::snapshot_in(Path::new(PROC_ROOT))
+    }
+
+    fn snapshot_in(proc_root: &Path) -> Option<Self> {
+        let mut open_files = Self::default();
+
+        for entry in fs::read_dir(proc_root).ok()?.filter_map(|entry| entry.ok()) {
+            let Some(pid) = entry.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) else {
+                continue;
+            };
+
+            let process_dir = entry.path();
+            let name = fs::read_to_string(process_dir.join("comm"))
+                .map(|comm| comm.trim_end().to_string())
+                .unwrap_or_default();
+
+            let descriptors = fs::read_dir(process_dir.join("fd"))
+                .into_iter()
+                .flatten()
+                .filter_map(|fd| fs::read_link(fd.ok()?.path()).ok());
+            let mappings = fs::read_to_string(process_dir.join("maps"))
+                .map(|maps| maps.lines().filter_map(mapped_path).collect::<Vec<_>>())
+                .unwrap_or_default();
+
+            for path in descriptors.chain(mappings) {
+                let holders = open_files.holders.entry(path).or_default();
+                if !holders.iter().any(|holder| holder.pid == pid) {
+                    holders.push(FileHolder { pid, name: name.clone() });
+                }
+            }
+        }
+
+        Some(open_files)
+    }
+
+    /// Verifica se o caminho está aberto por algum processo.
+    pub(super) fn contains(&self, path: &Path) -> bool {
+        self.holders.contains_key(path)
+    }
+
+    /// Processos que usam o caminho ou, se for um diretório, algo abaixo dele.
+    pub(super) fn holders_under(&self, path: &Path) -> Vec<&FileHolder> {
+        let mut holders: Vec<&FileHolder> = self
+            .holders
+            .range(path.to_path_buf()..)
+            .take_while(|(held, _)| held.starts_with(path))
+            .flat_map(|(_, holders)| holders)
+            .collect();
+
+        holders.sort_by_key(|holder| holder.pid);
+        holders.dedup_by_key(|holder| holder.pid);
+        holders
+    }
+}
+
+/// Caminho de uma linha do `maps` (`endereço perms offset dev inode caminho`).
+/// Regiões anônimas (`[heap]`, `[stack]`) não têm caminho.
+fn mapped_path(line: &str) -> Option<PathBuf> {
+    let path = &line[line.find('/')?..];
+    Some(PathBuf::from(path.strip_suffix(DELETED_SUFFIX).unwrap_or(path)))
+}
+
+/// Descreve quem usa um arquivo, como `em uso por firefox (PID 1234)`.
+pub(super) fn describe_holders(holders: &[&FileHolder]) -> String {
+    let names: Vec<String> = holders
+        .iter()
+        .map(|holder| format!("{} (PID {})", holder.name, holder.pid))
+        .collect();
+    format!("em uso por {}", names.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn should_find_files_open_in_this_process() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().canonicalize().unwrap().join("open.log");
+        let _held = File::create(&path).unwrap();
+
+        let open = OpenFiles::snapshot().unwrap();
+        let holders = open.holders_under(path.parent().unwrap());
+
+        assert!(open.contains(&path));
+        assert_eq!(holders.len(), 1);
+        assert_eq!(holders[0].pid, std::process::id());
+        assert!(describe_holders(&holders).ends_with(&format!("(PID {})", std::process::id())));
+        assert!(OpenFiles::snapshot_in(&dir.path().join("sem-proc")).is_none());
+    }
+
+    #[test]
+    fn should_read_paths_of_memory_mappings() {
+        assert_eq!(
+            mapped_path("7f1c2a000000-7f1c2a021000 r--p 00000000 08:01 131 /usr/lib/libc.so.6"),
+            Some(PathBuf::from("/usr/lib/libc.so.6"))
+        );
+        assert_eq!(
+            mapped_path("7f1c2a000000-7f1c2a021000 rw-s 00000000 00:05 99 /tmp/cache db (deleted)"),
+            Some(PathBuf::from("/tmp/cache db"))
+        );
+        assert_eq!(mapped_path("55d0c5e4d000-55d0c5e6e000 rw-p 00000000 00:00 0   [heap]"), None);
+    }
+}
@@ -0,0 +1,237 @@
+use crate::domain::entities::CleanableItem;
+use crate::domain::repositories::ScanProgressObserver;
+use crate::domain::value_objects::{CategoryType, ItemKind, ScanProgress, CancellationToken, Truncation};
+use crate::infrastructure::config::ExclusionRules;
+use walkdir::{DirEntry, FilterEntry, IntoIter, WalkDir};
+use std::cell::{Cell, RefCell};
+use std::collections::HashSet;
+use std::fs::{self, Metadata};
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+/// Tamanho unitário dos blocos informados por `st_blocks`.
+const BLOCK_SIZE: u64 = 512;
+
+/// Tamanho aparente (`len`) e ocupado em disco (blocos alocados) de um caminho.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(super) struct DiskUsage {
+    pub(super) apparent: u64,
+    pub(super) on_disk: u64,
+}
+
+impl DiskUsage {
+    /// Lê os tamanhos dos metadados, sem considerar hardlinks.
+    pub(super) fn of(metadata: &Metadata) -> Self {
+        Self {
+            apparent: metadata.len(),
+            on_disk: metadata.blocks() * BLOCK_SIZE,
+        }
+    }
+
+    pub(super) fn add(&mut self, other: DiskUsage) {
+        self.apparent += other.apparent;
+        self.on_disk += other.on_disk;
+    }
+}
+
+/// Cria um item do sistema de arquivos com tipo, datas e dono lidos dos metadados.
+/// `usage` é informado à parte porque diretórios somam o conteúdo.
+pub(super) fn item_from_metadata(path: &Path, metadata: &Metadata, usage: DiskUsage) -> CleanableItem {
+    let kind = if metadata.file_type().is_symlink() {
+        ItemKind::Symlink
+    } else if metadata.is_dir() {
+        ItemKind::Directory
+    } else {
+        ItemKind::File
+    };
+
+    CleanableItem::new(path.to_string_lossy().to_string(), usage.on_disk)
+        .with_apparent_size(usage.apparent)
+        .with_kind(kind)
+        .with_times(metadata.modified().ok(), metadata.accessed().ok())
+        .with_owner(metadata.uid())
+}
+
+/// Totais acumulados durante uma varredura, compartilhados entre as threads.
+#[derive(Default)]
+pub(super) struct ScanTotals {
+    bytes: AtomicU64,
+    items: AtomicUsize,
+    completed_categories: AtomicUsize,
+}
+
+impl ScanTotals {
+    /// Registra o fim de uma categoria e retorna quantas já terminaram.
+    pub(super) fn complete_category(&self) -> usize {
+        self.completed_categories.fetch_add(1, Ordering::Relaxed) + 1
+    }
+}
+
+/// Contexto de varredura de uma categoria.
+/// Encaminha o progresso da categoria ao observador, aplica as regras de
+/// exclusão, conta cada inode uma única vez, não entra nas raízes de outras
+/// categorias e acumula o que foi deixado de fora por limites configurados.
+pub(super) struct ScanContext<'a> {
+    pub(super) category: CategoryType,
+    observer: &'a dyn ScanProgressObserver,
+    totals: &'a ScanTotals,
+    cancellation: &'a CancellationToken,
+    exclusions: &'a ExclusionRules,
+    same_file_system: bool,
+    claimed_roots: Vec<PathBuf>,
+    truncation: Cell<Truncation>,
+    seen_inodes: RefCell<HashSet<(u64, u64)>>,
+}
+
+impl<'a> ScanContext<'a> {
+    pub(super) fn new(
+        category: CategoryType,
+        observer: &'a dyn ScanProgressObserver,
+        totals: &'a ScanTotals,
+        cancellation: &'a CancellationToken,
+        exclusions: &'a ExclusionRules,
+    ) -> Self {
+        Self {
+            category,
+            observer,
+            totals,
+            cancellation,
+            exclusions,
+            same_file_system: true,
+            claimed_roots: Vec::new(),
+            truncation: Cell::new(Truncation::default()),
+            seen_inodes: RefCell::new(HashSet::new()),
+        }
+    }
+
+    /// Permite que os percursos entrem em outros sistemas de arquivos montados abaixo das raízes.
+    pub(super) fn with_same_file_system(mut self, same_file_system: bool) -> Self {
+        self.same_file_system = same_file_system;
+        self
+    }
+
+    /// Diretórios varridos por outras categorias, que os percursos desta não atravessam.
+    pub(super) fn with_claimed_roots(mut self, roots: Vec<PathBuf>) -> Self {
+        self.claimed_roots = roots
+            .into_iter()
+            .map(|root| fs::canonicalize(&root).unwrap_or(root))
+            .collect();
+        self
+    }
+
+    /// Retorna o que foi deixado de fora até agora.
+    pub(super) fn truncation(&self) -> Truncation {
+        self.truncation.get()
+    }
+
+    /// Registra itens não listados por causa de um limite.
+    pub(super) fn skip(&self, items: usize, estimated_bytes: u64) {
+        let current = self.truncation.get();
+        self.truncation.set(Truncation::new(
+            current.skipped_items() + items,
+            current.estimated_skipped_bytes() + estimated_bytes,
+        ));
+    }
+
+    /// Verifica se a varredura deve continuar.
+    pub(super) fn is_active(&self) -> bool {
+        !self.cancellation.is_cancelled()
+    }
+
+    /// Notifica a entrada em um diretório.
+    pub(super) fn enter_directory(&self, path: &Path) {
+        self.observer.on_progress(ScanProgress::DirectoryEntered {
+            category: self.category,
+            path: path.to_string_lossy().to_string(),
+        });
+    }
+
+    /// Notifica diretórios visitados por um WalkDir.
+    pub(super) fn visit(&self, entry: &DirEntry) {
+        if entry.file_type().is_dir() {
+            self.enter_directory(entry.path());
+        }
+    }
+
+    /// Mede um arquivo pelos blocos alocados.
+    /// Hardlinks já vistos nesta categoria (mesmo dispositivo e inode) ocupam 0 bytes.
+    pub(super) fn usage(&self, metadata: &Metadata) -> DiskUsage {
+        let mut usage = DiskUsage::of(metadata);
+
+        if metadata.nlink() > 1
+            && !self.seen_inodes.borrow_mut().insert((metadata.dev(), metadata.ino()))
+        {
+            usage.on_disk = 0;
+        }
+        usage
+    }
+
+    /// Verifica se um caminho está protegido pelas regras de exclusão.
+    pub(super) fn is_excluded(&self, path: &Path) -> bool {
+        self.exclusions.is_excluded(path)
+    }
+
+    /// Percorre `root` até `max_depth` sem entrar em caminhos excluídos
+    /// nem em raízes de outras categorias.
+    /// Links simbólicos nunca são seguidos, nem quando a própria raiz é um
+    /// (ex.: `~/Projects` apontando para um NFS): nesse caso nada é percorrido.
+    /// Por padrão o percurso também não atravessa pontos de montagem.
+    pub(super) fn walk(
+        &self,
+        root: &Path,
+        max_depth: usize,
+    ) -> FilterEntry<IntoIter, impl FnMut(&DirEntry) -> bool + use<'a>> {
+        let exclusions = self.exclusions;
+        let claimed_roots = self.claimed_roots.clone();
+        // Só os diretórios acima da raiz são resolvidos, nunca a raiz em si
+        let root = match (root.parent().and_then(|parent| fs::canonicalize(parent).ok()), root.file_name()) {
+            (Some(parent), Some(name)) => parent.join(name),
+            _ => root.to_path_buf(),
+        };
+
+        WalkDir::new(root)
+            .max_depth(max_depth)
+            .follow_links(false)
+            .follow_root_links(false)
+            .same_file_system(self.same_file_system)
+            .into_iter()
+            .filter_entry(move |entry| {
+                if entry.depth() == 0 && entry.path_is_symlink() {
+                    return false;
+                }
+                if claimed_roots.iter().any(|claimed| entry.path() == claimed) {
+                    return false;
+                }
+
+                // A raiz também é checada contra os diretórios acima dela
+                if entry.depth() == 0 {
+                    !exclusions.is_excluded(entry.path())
+                } else {
+                    !exclusions.matches(entry.path(), entry.file_type().is_dir())
+                }
+            })
+    }
+
+    /// Percorre os arquivos e links simbólicos sob `root` até `max_depth`,
+    /// notificando os diretórios visitados e parando quando a varredura é cancelada.
+    pub(super) fn files(&self, root: &Path, max_depth: usize) -> impl Iterator<Item = DirEntry> + use<'_, 'a> {
+        self.walk(root, max_depth)
+            .take_while(|_| self.is_active())
+            .filter_map(|e| e.ok())
+            .inspect(|e| self.visit(e))
+            .filter(|e| !e.file_type().is_dir())
+    }
+
+    /// Registra um item encontrado e atualiza os totais.
+    pub(super) fn item_found(&self, size: u64) {
+        let total_bytes = self.totals.bytes.fetch_add(size, Ordering::Relaxed) + size;
+        let total_items = self.totals.items.fetch_add(1, Ordering::Relaxed) + 1;
+
+        self.observer.on_progress(ScanProgress::ItemFound {
+            category: self.category,
+            total_bytes,
+            total_items,
+        });
+    }
+}
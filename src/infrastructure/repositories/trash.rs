@@ -0,0 +1,259 @@
+use anyhow::{Result, anyhow};
+use chrono::{Local, NaiveDateTime, TimeZone};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Extensão dos arquivos de informação em `info/`.
+const TRASH_INFO_EXTENSION: &str = "trashinfo";
+
+/// Cache de tamanhos dos diretórios apagados, mantido pelos gerenciadores de arquivos.
+const DIRECTORY_SIZES_FILE: &str = "directorysizes";
+
+/// Item de uma lixeira freedesktop, lido de `info/<nome>.trashinfo`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct TrashEntry {
+    /// Caminho do item em `files/`.
+    pub(super) path: PathBuf,
+    /// Onde o item estava antes de ir para a lixeira.
+    pub(super) original_path: PathBuf,
+    pub(super) deleted_at: Option<SystemTime>,
+}
+
+/// Lista os itens de uma lixeira que ainda têm conteúdo em `files/`.
+pub(super) fn trash_entries(trash_dir: &Path) -> Vec<TrashEntry> {
+    let Ok(infos) = fs::read_dir(trash_dir.join("info")) else {
+        return Vec::new();
+    };
+
+    let mut entries: Vec<TrashEntry> = infos
+        .filter_map(|info| info.ok())
+        .filter_map(|info| {
+            let info_path = info.path();
+            if info_path.extension().is_none_or(|ext| ext != TRASH_INFO_EXTENSION) {
+                return None;
+            }
+
+            let path = trash_dir.join("files").join(info_path.file_stem()?);
+            fs::symlink_metadata(&path).ok()?;
+
+            let content = fs::read_to_string(&info_path).ok()?;
+            let (original_path, deleted_at) = parse_trash_info(&content, trash_dir)?;
+
+            Some(TrashEntry { path, original_path, deleted_at })
+        })
+        .collect();
+
+    entries.sort_by(|a, b| a.path.cmp(&b.path));
+    entries
+}
+
+/// Interpreta um `.trashinfo`.
+/// Caminhos relativos (lixeiras de pontos de montagem) partem do topo da montagem.
+fn parse_trash_info(content: &str, trash_dir: &Path) -> Option<(PathBuf, Option<SystemTime>)> {
+    let mut in_section = false;
+    let mut original_path = None;
+    let mut deleted_at = None;
+
+    for line in content.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_section = line == "[Trash Info]";
+            continue;
+        }
+        if !in_section {
+            continue;
+        }
+
+        match line.split_once('=') {
+            Some(("Path", value)) => original_path = Some(PathBuf::from(percent_decode(value))),
+            Some(("DeletionDate", value)) => deleted_at = parse_deletion_date(value),
+            _ => {}
+        }
+    }
+
+    let original_path = original_path?;
+    if original_path.is_absolute() {
+        return Some((original_path, deleted_at));
+    }
+
+    let top_dir = trash_top_dir(trash_dir)?;
+    Some((top_dir.join(original_path), deleted_at))
+}
+
+/// Topo do ponto de montagem de `$topdir/.Trash-$uid` ou `$topdir/.Trash/$uid`.
+fn trash_top_dir(trash_dir: &Path) -> Option<&Path> {
+    let parent = trash_dir.parent()?;
+    if parent.file_name().is_some_and(|name| name == ".Trash") {
+        parent.parent()
+    } else {
+        Some(parent)
+    }
+}
+
+/// `DeletionDate` usa o formato `AAAA-MM-DDThh:mm:ss` no horário local.
+fn parse_deletion_date(value: &str) -> Option<SystemTime> {
+    let naive = NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S").ok()?;
+    Local.from_local_datetime(&naive).earliest().map(SystemTime::from)
+}
+
+/// Decodifica sequências `%XX` como no campo `Path` e no `directorysizes`.
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let escaped = bytes.get(index + 1..index + 3)
+            .filter(|_| bytes[index] == b'%')
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .and_then(|digits| u8::from_str_radix(digits, 16).ok());
+
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Retorna a lixeira de um caminho `<lixeira>/files/<nome>`.
+pub(super) fn trash_dir_of(path: &Path) -> Option<&Path> {
+    let files_dir = path.parent()?;
+    if files_dir.file_name()? != "files" {
+        return None;
+    }
+    files_dir.parent()
+}
+
+/// Conclui a remoção de um item cujo conteúdo em `files/` já foi apagado:
+/// remove o `.trashinfo` e a linha correspondente do `directorysizes`.
+pub(super) fn forget_trash_entry(path: &Path) -> Result<()> {
+    let trash_dir = trash_dir_of(path).ok_or_else(|| anyhow!("{} não está em uma lixeira", path.display()))?;
+    let name = path.file_name().ok_or_else(|| anyhow!("Item da lixeira sem nome"))?;
+
+    let mut info_name = name.to_os_string();
+    info_name.push(".");
+    info_name.push(TRASH_INFO_EXTENSION);
+    match fs::remove_file(trash_dir.join("info").join(info_name)) {
+        Err(e) if e.kind() != ErrorKind::NotFound => return Err(e.into()),
+        _ => {}
+    }
+
+    remove_directory_size(trash_dir, &name.to_string_lossy())
+}
+
+/// Reescreve o `directorysizes` sem a linha do item (`tamanho mtime nome-codificado`).
+/// A troca é feita por renomeação para nunca deixar o arquivo pela metade.
+fn remove_directory_size(trash_dir: &Path, name: &str) -> Result<()> {
+    let sizes_path = trash_dir.join(DIRECTORY_SIZES_FILE);
+    let Ok(content) = fs::read_to_string(&sizes_path) else {
+        return Ok(());
+    };
+
+    let kept: Vec<&str> = content
+        .lines()
+        .filter(|line| {
+            line.splitn(3, ' ')
+                .nth(2)
+                .is_none_or(|encoded| percent_decode(encoded) != name)
+        })
+        .collect();
+
+    if kept.len() == content.lines().count() {
+        return Ok(());
+    }
+
+    let temp_path = trash_dir.join(format!("{}.{}", DIRECTORY_SIZES_FILE, std::process::id()));
+    let mut updated = kept.join("\n");
+    if !updated.is_empty() {
+        updated.push('\n');
+    }
+    fs::write(&temp_path, updated)?;
+    fs::rename(&temp_path, &sizes_path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trash_item(trash_dir: &Path, name: &str, info: &str) {
+        fs::create_dir_all(trash_dir.join("files")).unwrap();
+        fs::create_dir_all(trash_dir.join("info")).unwrap();
+        fs::write(trash_dir.join("files").join(name), b"conteudo").unwrap();
+        fs::write(trash_dir.join("info").join(format!("{}.trashinfo", name)), info).unwrap();
+    }
+
+    #[test]
+    fn should_read_original_path_and_deletion_date() {
+        let dir = tempfile::tempdir().unwrap();
+        let trash_dir = dir.path().join("Trash");
+        trash_item(
+            &trash_dir,
+            "relatório.pdf",
+            "[Trash Info]\nPath=/home/dev/Docs/relat%C3%B3rio%20final.pdf\nDeletionDate=2024-03-01T10:30:00\n",
+        );
+
+        let entries = trash_entries(&trash_dir);
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].path, trash_dir.join("files/relatório.pdf"));
+        assert_eq!(entries[0].original_path, PathBuf::from("/home/dev/Docs/relatório final.pdf"));
+        let expected = Local.with_ymd_and_hms(2024, 3, 1, 10, 30, 0).unwrap();
+        assert_eq!(entries[0].deleted_at, Some(SystemTime::from(expected)));
+    }
+
+    #[test]
+    fn should_resolve_relative_paths_from_mount_top_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let trash_dir = dir.path().join(".Trash-1000");
+        trash_item(&trash_dir, "a.txt", "[Trash Info]\nPath=projetos/a.txt\nDeletionDate=bad\n");
+
+        let entries = trash_entries(&trash_dir);
+
+        assert_eq!(entries[0].original_path, dir.path().join("projetos/a.txt"));
+        assert_eq!(entries[0].deleted_at, None);
+    }
+
+    #[test]
+    fn should_skip_info_files_without_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let trash_dir = dir.path().join("Trash");
+        trash_item(&trash_dir, "a.txt", "[Trash Info]\nPath=/a.txt\n");
+        fs::remove_file(trash_dir.join("files/a.txt")).unwrap();
+
+        assert!(trash_entries(&trash_dir).is_empty());
+    }
+
+    #[test]
+    fn should_forget_info_and_directory_size_of_removed_entry() {
+        let dir = tempfile::tempdir().unwrap();
+        let trash_dir = dir.path().join("Trash");
+        trash_item(&trash_dir, "meu dir", "[Trash Info]\nPath=/meu%20dir\n");
+        trash_item(&trash_dir, "outro", "[Trash Info]\nPath=/outro\n");
+        fs::write(
+            trash_dir.join(DIRECTORY_SIZES_FILE),
+            "4096 1700000000 meu%20dir\n8192 1700000000 outro\n",
+        )
+        .unwrap();
+
+        let path = trash_dir.join("files/meu dir");
+        fs::remove_file(&path).unwrap();
+        forget_trash_entry(&path).unwrap();
+
+        assert!(!trash_dir.join("info/meu dir.trashinfo").exists());
+        assert!(trash_dir.join("info/outro.trashinfo").exists());
+        assert_eq!(
+            fs::read_to_string(trash_dir.join(DIRECTORY_SIZES_FILE)).unwrap(),
+            "8192 1700000000 outro\n"
+        );
+    }
+}
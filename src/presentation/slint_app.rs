@@ -1,12 +1,21 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
 use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::SystemTime;
 use slint::{ModelRc, VecModel, SharedString, Model};
 
 // Incluir o código UI gerado pelo Slint
 slint::include_modules!();
 
-use crate::application::{ScanSystemUseCase, CleanSelectedCategoriesUseCase};
-use crate::domain::entities::ScanResult;
-use crate::infrastructure::{FileSystemScannerRepository, FileSystemCleanerRepository};
+use crate::application::{ScanSystemUseCase, CleanSelectedCategoriesUseCase, CategorySelection};
+use crate::domain::entities::{CleanReport, CleanableCategory, CleanableItem, ScanResult};
+use crate::domain::repositories::ScanProgressObserver;
+use crate::domain::value_objects::{CategoryType, ScanProgress, CancellationToken};
+use crate::infrastructure::{AppConfig, FileSystemScannerRepository, FileSystemCleanerRepository};
+use crate::infrastructure::config::ExclusionRules;
+use crate::infrastructure::platform::default_platform_paths;
+use crate::shared::format_bytes;
 
 /// Executa a aplicação Slint
 pub fn run_app() -> Result<(), slint::PlatformError> {
@@ -14,12 +23,326 @@ pub fn run_app() -> Result<(), slint::PlatformError> {
     app.run()
 }
 
+/// Último estado conhecido do progresso da varredura.
+/// Com vários workers, mais de uma categoria pode estar em andamento.
+#[derive(Debug, Clone, Default)]
+struct ScanProgressState {
+    running: Vec<CategoryType>,
+    completed: usize,
+    total: usize,
+    path: String,
+    total_bytes: u64,
+    total_items: usize,
+    fraction: f32,
+}
+
+impl ScanProgressState {
+    fn apply(&mut self, progress: ScanProgress) {
+        let category = progress.category();
+        if !matches!(progress, ScanProgress::CategoryFinished { .. }) && !self.running.contains(&category) {
+            self.running.push(category);
+        }
+
+        match progress {
+            ScanProgress::CategoryStarted { .. } => {}
+            ScanProgress::DirectoryEntered { path, .. } => {
+                self.path = path;
+            }
+            ScanProgress::ItemFound { total_bytes, total_items, .. } => {
+                self.total_bytes = total_bytes;
+                self.total_items = total_items;
+            }
+            ScanProgress::CategoryFinished { completed_categories, total_categories, .. } => {
+                self.running.retain(|running| *running != category);
+                self.completed = completed_categories;
+                self.total = total_categories;
+                self.fraction = completed_categories as f32 / total_categories.max(1) as f32;
+            }
+        }
+    }
+
+    /// Categorias em andamento, na ordem em que começaram, e quantas já terminaram.
+    fn label(&self) -> String {
+        let running: Vec<&str> = self.running.iter().map(|category| category.display_name()).collect();
+        if running.is_empty() || self.total == 0 {
+            return running.join(", ");
+        }
+        format!("{} · {}/{}", running.join(", "), self.completed, self.total)
+    }
+}
+
+/// Observador que repassa o progresso da varredura para a janela.
+/// Os eventos são agrupados para no máximo uma atualização pendente por vez.
+struct UiScanProgress {
+    window: slint::Weak<AppWindow>,
+    state: Arc<Mutex<ScanProgressState>>,
+    update_pending: Arc<AtomicBool>,
+}
+
+impl UiScanProgress {
+    fn new(window: slint::Weak<AppWindow>) -> Self {
+        Self {
+            window,
+            state: Arc::new(Mutex::new(ScanProgressState::default())),
+            update_pending: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+impl ScanProgressObserver for UiScanProgress {
+    fn on_progress(&self, progress: ScanProgress) {
+        self.state.lock().unwrap().apply(progress);
+
+        if self.update_pending.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let window_weak = self.window.clone();
+        let state = Arc::clone(&self.state);
+        let update_pending = Arc::clone(&self.update_pending);
+
+        slint::invoke_from_event_loop(move || {
+            update_pending.store(false, Ordering::Release);
+            let state = state.lock().unwrap().clone();
+
+            if let Some(window) = window_weak.upgrade() {
+                window.set_scan_progress(state.fraction);
+                window.set_scan_category(SharedString::from(state.label()));
+                window.set_scan_path(SharedString::from(state.path));
+                window.set_scan_found_size(SharedString::from(format_bytes(state.total_bytes)));
+                window.set_scan_found_items(state.total_items as i32);
+            }
+        }).ok();
+    }
+}
+
+/// Resume tipo, idade e dono de um item para a lista de revisão.
+fn describe_item(item: &CleanableItem, now: SystemTime) -> String {
+    let mut parts = vec![item.kind().display_name().to_string()];
+
+    if let Some(state) = item.log_state() {
+        parts.push(state.display_name().to_string());
+    }
+    if let Some(ecosystem) = item.ecosystem() {
+        parts.push(ecosystem.display_name().to_string());
+    }
+    if let Some(idle) = item.project_idle_age(now) {
+        parts.push(format!("projeto sem alterações há {} dias", idle.as_secs() / 86_400));
+    }
+
+    if !item.parts().is_empty() {
+        parts.push(format!("{} diretórios de cache", item.parts().len()));
+    }
+
+    if let Some(exe) = item.crashed_exe() {
+        parts.push(exe.to_string());
+    }
+    if let Some(age) = item.crash_age(now) {
+        parts.push(format!("falhou há {} dias", age.as_secs() / 86_400));
+    }
+
+    if let Some(original) = item.trashed_from() {
+        parts.push(format!("de {}", original));
+    }
+    if let Some(age) = item.trashed_age(now) {
+        parts.push(format!("na lixeira há {} dias", age.as_secs() / 86_400));
+    }
+
+    if let Some(age) = item.modified_age(now) {
+        parts.push(format!("modificado há {} dias", age.as_secs() / 86_400));
+    }
+    if let Some(age) = item.accessed_age(now) {
+        parts.push(format!("acessado há {} dias", age.as_secs() / 86_400));
+    }
+    if let Some(uid) = item.owner_uid() {
+        parts.push(format!("uid {}", uid));
+    }
+
+    parts.join(" · ")
+}
+
+/// Mostra o tamanho em disco e, quando diferente, o aparente.
+fn describe_size(item: &CleanableItem) -> String {
+    if !item.is_size_known() {
+        "tamanho desconhecido".to_string()
+    } else if item.apparent_size() == item.size_in_bytes() {
+        format_bytes(item.size_in_bytes())
+    } else {
+        format!("{} ({} aparente)", format_bytes(item.size_in_bytes()), format_bytes(item.apparent_size()))
+    }
+}
+
+/// Resume a limpeza: itens removidos e o primeiro item deixado de fora, com o motivo.
+fn describe_report(report: &CleanReport) -> String {
+    let mut removed = format!("{} items removed", report.removed_count());
+    if !report.partial().is_empty() {
+        let freed: u64 = report.partial().iter().map(|partial| partial.freed_bytes).sum();
+        removed = format!("{} • {} partially ({} freed)", removed, report.partial().len(), format_bytes(freed));
+    }
+    match report.skipped() {
+        [] => removed,
+        [first, rest @ ..] => format!(
+            "{} • {} skipped ({}: {}{})",
+            removed,
+            rest.len() + 1,
+            first.path,
+            first.reason,
+            if rest.is_empty() { "" } else { ", …" },
+        ),
+    }
+}
+
+/// Agrupa os artefatos de build por projeto, do maior para o menor.
+/// O detalhe lista ecossistemas e artefatos, cada um com há quanto tempo
+/// as fontes do seu projeto estão paradas.
+fn project_groups(category: &CleanableCategory, now: SystemTime) -> Vec<ProjectData> {
+    let mut groups: BTreeMap<&str, Vec<&CleanableItem>> = BTreeMap::new();
+    for item in category.items() {
+        if let Some(project) = item.project() {
+            groups.entry(project).or_default().push(item);
+        }
+    }
+
+    let mut groups: Vec<_> = groups
+        .into_iter()
+        .map(|(project, items)| (project, items.iter().map(|item| item.size_in_bytes()).sum::<u64>(), items))
+        .collect();
+    groups.sort_by_key(|(_, size, _)| std::cmp::Reverse(*size));
+
+    groups
+        .into_iter()
+        .map(|(project, size, items)| {
+            let ecosystems: BTreeSet<&str> = items
+                .iter()
+                .filter_map(|item| item.ecosystem())
+                .map(|ecosystem| ecosystem.display_name())
+                .collect();
+            let artifacts: Vec<String> = items
+                .iter()
+                .filter_map(|item| {
+                    let name = Path::new(item.path()).file_name()?.to_string_lossy();
+                    Some(match item.project_idle_age(now) {
+                        Some(idle) => format!("{} (sem alterações há {} dias)", name, idle.as_secs() / 86_400),
+                        None => name.to_string(),
+                    })
+                })
+                .collect();
+
+            let detail = [
+                ecosystems.into_iter().collect::<Vec<_>>().join(", "),
+                artifacts.join(", "),
+            ];
+
+            ProjectData {
+                path: SharedString::from(project),
+                detail: SharedString::from(detail.join(" · ")),
+                size: SharedString::from(format_bytes(size)),
+            }
+        })
+        .collect()
+}
+
+/// Ícone exibido no card de cada categoria.
+fn category_icon(category_type: Option<CategoryType>) -> &'static str {
+    match category_type {
+        Some(CategoryType::LogFiles) => "📋",
+        Some(CategoryType::TemporaryFiles) => "🗂️",
+        Some(CategoryType::DockerImages | CategoryType::DockerVolumes) => "🐳",
+        Some(CategoryType::DevelopmentPackages) => "📦",
+        Some(CategoryType::DuplicateFiles) => "👯",
+        Some(CategoryType::LargeFiles) => "🐘",
+        Some(CategoryType::Trash) => "🗑️",
+        Some(CategoryType::BrowserCaches) => "🌐",
+        Some(CategoryType::RustArtifacts) => "🦀",
+        Some(CategoryType::SystemJournal) => "📰",
+        Some(CategoryType::CrashDumps) => "💥",
+        Some(CategoryType::UnusedRuntimes) => "🧩",
+        Some(CategoryType::DockerBuildCache) => "🧱",
+        Some(CategoryType::DockerContainers) => "🚢",
+        None => "📁",
+    }
+}
+
+/// Converte as categorias do resultado em linhas da UI.
+fn category_rows(result: &ScanResult) -> Vec<CategoryData> {
+    let now = SystemTime::now();
+    result.categories()
+        .iter()
+        .map(|cat| {
+            let size_gb = cat.total_size() as f64 / (1024.0 * 1024.0 * 1024.0);
+            let apparent_gb = cat.total_apparent_size() as f64 / (1024.0 * 1024.0 * 1024.0);
+            let icon = category_icon(cat.category_type());
+            
+            // Itens sem tamanho medido não entram no total, mas são avisados
+            let total_size = match cat.unknown_size_count() {
+                0 => format!("{:.2} GB", size_gb),
+                unknown => format!("{:.2} GB + {} sem tamanho", size_gb, unknown),
+            };
+
+            let truncation_note = cat.truncation()
+                .map(|truncation| format!(
+                    "Limit reached: {} more items not listed (~{})",
+                    truncation.skipped_items(),
+                    format_bytes(truncation.estimated_skipped_bytes()),
+                ))
+                .unwrap_or_default();
+            
+            // Itens são listados nas categorias revisadas item a item e nos
+            // caches de navegador, em que cada item é um perfil
+            let lists_items = cat.requires_review() || cat.category_type() == Some(CategoryType::BrowserCaches);
+            let items: Vec<ItemData> = if lists_items {
+                cat.items()
+                    .iter()
+                    .map(|item| ItemData {
+                        path: SharedString::from(item.path()),
+                        size: SharedString::from(describe_size(item)),
+                        detail: SharedString::from(describe_item(item, now)),
+                        selected: false,
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            
+            CategoryData {
+                name: SharedString::from(cat.name()),
+                items_count: cat.items().len() as i32,
+                total_size: SharedString::from(total_size),
+                apparent_size: SharedString::from(format!("{:.2} GB", apparent_gb)),
+                selected: false,
+                icon: SharedString::from(icon),
+                truncated: cat.truncation().is_some(),
+                truncation_note: SharedString::from(truncation_note),
+                scan_error: SharedString::from(cat.scan_error().unwrap_or_default()),
+                requires_review: cat.requires_review(),
+                items: ModelRc::new(VecModel::from(items)),
+                projects: ModelRc::new(VecModel::from(project_groups(cat, now))),
+            }
+        })
+        .collect()
+}
+
+/// Exibe os totais e as categorias na janela.
+/// Deve ser chamada no thread da UI, pois cria os models do Slint.
+fn show_results(window: &AppWindow, result: &ScanResult) {
+    let total_gb = result.total_size() as f64 / (1024.0 * 1024.0 * 1024.0);
+    let apparent_gb = result.total_apparent_size() as f64 / (1024.0 * 1024.0 * 1024.0);
+    window.set_total_size_display(SharedString::from(format!("{:.2}", total_gb)));
+    window.set_total_apparent_display(SharedString::from(format!("{:.2} GB", apparent_gb)));
+    window.set_total_items(result.total_items() as i32);
+    
+    // Criar model para as categorias
+    let cat_model = std::rc::Rc::new(VecModel::from(category_rows(result)));
+    window.set_categories(ModelRc::from(cat_model));
+}
+
 /// Aplicação principal usando Slint
 pub struct SlintApp {
     window: AppWindow,
     scan_use_case: Arc<ScanSystemUseCase>,
     clean_use_case: Arc<CleanSelectedCategoriesUseCase>,
     scan_results: Arc<Mutex<Option<ScanResult>>>,
+    active_operation: Arc<Mutex<CancellationToken>>,
 }
 
 impl SlintApp {
@@ -27,14 +350,36 @@ impl SlintApp {
         // Criar window
         let window = AppWindow::new().expect("Failed to create window");
         
+        // Configuração do usuário, com padrões se ausente ou inválida
+        let config = AppConfig::load().unwrap_or_else(|e| {
+            eprintln!("Erro ao carregar configuração: {:#}", e);
+            AppConfig::default()
+        });
+        
+        // Os padrões já foram validados ao carregar a configuração
+        let exclusions = config.exclusions
+            .rules(default_platform_paths().home())
+            .unwrap_or_else(|e| {
+                eprintln!("Erro nas exclusões: {:#}", e);
+                ExclusionRules::default()
+            });
+        
         // Injeção de dependências
-        let scanner_repo = Arc::new(FileSystemScannerRepository::new());
-        let cleaner_repo = Arc::new(FileSystemCleanerRepository::new());
+        let cleaner_repo = Arc::new(
+            FileSystemCleanerRepository::new()
+                .with_exclusions(exclusions.clone())
+                .with_journal(config.scan.journal.clone())
+                .with_same_file_system(config.scan.same_file_system),
+        );
+        let scanner_repo = Arc::new(
+            FileSystemScannerRepository::with_config(config.scan).with_exclusions(exclusions),
+        );
         
         let scan_use_case = Arc::new(ScanSystemUseCase::new(scanner_repo));
         let clean_use_case = Arc::new(CleanSelectedCategoriesUseCase::new(cleaner_repo));
         
         let scan_results = Arc::new(Mutex::new(None));
+        let active_operation = Arc::new(Mutex::new(CancellationToken::new()));
         
         // Setup callbacks
         let mut app = Self {
@@ -42,6 +387,7 @@ impl SlintApp {
             scan_use_case,
             clean_use_case,
             scan_results,
+            active_operation,
         };
         
         app.setup_callbacks();
@@ -52,68 +398,53 @@ impl SlintApp {
         let window_weak = self.window.as_weak();
         let scan_use_case = Arc::clone(&self.scan_use_case);
         let scan_results = Arc::clone(&self.scan_results);
+        let active_operation = Arc::clone(&self.active_operation);
         
         // Callback: Start Scan
         self.window.on_start_scan(move || {
             if let Some(window) = window_weak.upgrade() {
                 window.set_is_scanning(true);
                 window.set_has_results(false);
+                window.set_scan_progress(0.0);
+                window.set_scan_category(SharedString::default());
+                window.set_scan_path(SharedString::default());
+                window.set_scan_found_size(SharedString::from(format_bytes(0)));
+                window.set_scan_found_items(0);
                 
                 let use_case = Arc::clone(&scan_use_case);
                 let results = Arc::clone(&scan_results);
                 let window_clone = window_weak.clone();
+                let progress = UiScanProgress::new(window_weak.clone());
+                
+                let cancellation = CancellationToken::new();
+                *active_operation.lock().unwrap() = cancellation.clone();
                 
                 std::thread::spawn(move || {
-                    if let Ok(result) = use_case.execute() {
-                        // Converter para dados da UI
-                        let total_size = result.total_size();
-                        let total_gb = total_size as f64 / (1024.0 * 1024.0 * 1024.0);
-                        let total_items = result.total_items();
-                        
-                        let categories: Vec<CategoryData> = result.categories()
-                            .iter()
-                            .map(|cat| {
-                                let size_gb = cat.total_size() as f64 / (1024.0 * 1024.0 * 1024.0);
-                                let icon = match cat.name().to_lowercase().as_str() {
-                                    name if name.contains("log") => "📋",
-                                    name if name.contains("cache") => "💾",
-                                    name if name.contains("download") => "⬇️",
-                                    name if name.contains("temp") => "🗂️",
-                                    name if name.contains("docker") => "🐳",
-                                    _ => "📁",
-                                };
-                                
-                                CategoryData {
-                                    name: SharedString::from(cat.name()),
-                                    items_count: cat.items().len() as i32,
-                                    total_size: SharedString::from(format!("{:.2} GB", size_gb)),
-                                    selected: false,
-                                    icon: SharedString::from(icon),
+                    match use_case.execute_with_progress(&progress, &cancellation) {
+                        Ok(result) => {
+                            let snapshot = result.clone();
+                            
+                            // Atualizar UI no thread principal
+                            slint::invoke_from_event_loop(move || {
+                                if let Some(window) = window_clone.upgrade() {
+                                    window.set_is_scanning(false);
+                                    window.set_has_results(true);
+                                    window.set_status_message(SharedString::default());
+                                    show_results(&window, &snapshot);
                                 }
-                            })
-                            .collect();
-                        
-                        // Atualizar UI no thread principal
-                        slint::invoke_from_event_loop(move || {
-                            if let Some(window) = window_clone.upgrade() {
-                                window.set_is_scanning(false);
-                                window.set_has_results(true);
-                                window.set_total_size_display(SharedString::from(format!("{:.2}", total_gb)));
-                                window.set_total_items(total_items as i32);
-                                
-                                // Criar model para as categorias
-                                let cat_model = std::rc::Rc::new(VecModel::from(categories));
-                                window.set_categories(ModelRc::from(cat_model.clone()));
-                            }
-                        }).ok();
-                        
-                        *results.lock().unwrap() = Some(result);
-                    } else {
-                        slint::invoke_from_event_loop(move || {
-                            if let Some(window) = window_clone.upgrade() {
-                                window.set_is_scanning(false);
-                            }
-                        }).ok();
+                            }).ok();
+                            
+                            *results.lock().unwrap() = Some(result);
+                        }
+                        Err(_) => {
+                            let message = if cancellation.is_cancelled() { "Scan stopped" } else { "Scan failed" };
+                            slint::invoke_from_event_loop(move || {
+                                if let Some(window) = window_clone.upgrade() {
+                                    window.set_is_scanning(false);
+                                    window.set_status_message(SharedString::from(message));
+                                }
+                            }).ok();
+                        }
                     }
                 });
             }
@@ -136,24 +467,49 @@ impl SlintApp {
             }
         });
         
+        // Callback: Item Selection Changed
+        let window_weak = self.window.as_weak();
+        self.window.on_item_selection_changed(move |index, item_index, checked| {
+            if let Some(window) = window_weak.upgrade() {
+                if let Some(category) = window.get_categories().row_data(index as usize) {
+                    if let Some(mut item) = category.items.row_data(item_index as usize) {
+                        item.selected = checked;
+                        category.items.set_row_data(item_index as usize, item);
+                    }
+                }
+            }
+        });
+        
         // Callback: Confirm Clean
         let window_weak = self.window.as_weak();
         let clean_use_case = Arc::clone(&self.clean_use_case);
         let scan_results = Arc::clone(&self.scan_results);
+        let active_operation = Arc::clone(&self.active_operation);
         
         self.window.on_confirm_clean(move || {
             if let Some(window) = window_weak.upgrade() {
                 let categories = window.get_categories();
                 
-                // Coletar índices selecionados
-                let mut selected_indices = Vec::new();
+                // Coletar categorias e itens selecionados
+                let mut selections = Vec::new();
                 for (i, category) in categories.iter().enumerate() {
-                    if category.selected {
-                        selected_indices.push(i);
+                    if category.requires_review {
+                        let item_indices: Vec<usize> = category.items
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, item)| item.selected)
+                            .map(|(j, _)| j)
+                            .collect();
+                        
+                        if !item_indices.is_empty() {
+                            selections.push(CategorySelection::items(i, item_indices));
+                        }
+                    } else if category.selected {
+                        selections.push(CategorySelection::whole(i));
                     }
                 }
                 
-                if selected_indices.is_empty() {
+                if selections.is_empty() {
                     return;
                 }
                 
@@ -162,32 +518,60 @@ impl SlintApp {
                 let use_case = Arc::clone(&clean_use_case);
                 let results = Arc::clone(&scan_results);
                 let window_clone = window_weak.clone();
+                let cancellation = CancellationToken::new();
+                *active_operation.lock().unwrap() = cancellation.clone();
                 
                 std::thread::spawn(move || {
-                    if let Some(scan_result) = results.lock().unwrap().as_ref() {
-                        match use_case.execute(scan_result, &selected_indices) {
-                            Ok(_cleaned_count) => {
-                                slint::invoke_from_event_loop(move || {
-                                    if let Some(window) = window_clone.upgrade() {
-                                        window.set_is_cleaning(false);
-                                        window.set_has_results(false);
-                                        window.set_categories(ModelRc::new(VecModel::default()));
-                                    }
-                                }).ok();
-                            }
-                            Err(_) => {
-                                slint::invoke_from_event_loop(move || {
-                                    if let Some(window) = window_clone.upgrade() {
-                                        window.set_is_cleaning(false);
-                                    }
-                                }).ok();
-                            }
+                    let mut guard = results.lock().unwrap();
+                    let Some(scan_result) = guard.as_mut() else {
+                        return;
+                    };
+                    
+                    match use_case.execute(scan_result, &selections, &cancellation) {
+                        Ok(report) if report.is_cancelled() => {
+                            // Manter na lista apenas o que não foi removido
+                            scan_result.remove_items(report.removed());
+                            let snapshot = scan_result.clone();
+                            let message = format!("Clean stopped: {}", describe_report(&report));
+                            
+                            slint::invoke_from_event_loop(move || {
+                                if let Some(window) = window_clone.upgrade() {
+                                    window.set_is_cleaning(false);
+                                    window.set_status_message(SharedString::from(message));
+                                    show_results(&window, &snapshot);
+                                }
+                            }).ok();
+                        }
+                        Ok(report) => {
+                            let message = describe_report(&report);
+                            
+                            slint::invoke_from_event_loop(move || {
+                                if let Some(window) = window_clone.upgrade() {
+                                    window.set_is_cleaning(false);
+                                    window.set_has_results(false);
+                                    window.set_status_message(SharedString::from(message));
+                                    window.set_categories(ModelRc::new(VecModel::default()));
+                                }
+                            }).ok();
+                        }
+                        Err(_) => {
+                            slint::invoke_from_event_loop(move || {
+                                if let Some(window) = window_clone.upgrade() {
+                                    window.set_is_cleaning(false);
+                                }
+                            }).ok();
                         }
                     }
                 });
             }
         });
         
+        // Callback: Stop
+        let active_operation = Arc::clone(&self.active_operation);
+        self.window.on_stop_operation(move || {
+            active_operation.lock().unwrap().cancel();
+        });
+        
         // Callback: Cancel Clean
         self.window.on_cancel_clean(move || {
             // Nada a fazer, dialog já fecha automaticamente